@@ -0,0 +1,375 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage:
+    simula-matrix-framework-rust <command> [options]
+
+Commands:
+    spreadsheet --kind <kind> -n <order> [--boxes <rows>x<columns>] [--output <file.ods>] [--threads <count>]
+        Export a table of all n by n latin squares as a spreadsheet.
+        Defaults to \"spreadsheets/<kind>_<order>.ods\".
+
+    latex --table <kind> -n <order> [--boxes <rows>x<columns>] [--output <file.tex>] [--threads <count>]
+        Print a table as a LaTeX longtable, or write it to a file.
+
+    With --boxes only the Sudokus with boxes of that size are included, where n = rows * columns.
+    This only works for the complete and summary tables.
+
+    The kind of table is one of:
+        complete      every latin square with its automorphisms and affine automorphisms
+        summary       the number of squares with every automorphism fingerprint
+        isotopy       one row per isotopy class, with its size and automorphism data
+        main-class    the same for main classes
+        mols          a set of mutually orthogonal latin squares of order n, from finite fields and their products
+
+    The classification runs on every available core unless --threads is given.
+
+    validate -n <order> [--threads <count>]
+        Classify every latin square of order n and check the recorded automorphisms and affine maps
+        against the squares, and that the \"Aut:\" sums agree with the squares and within every
+        fingerprint.
+
+    ascii -n <order>
+        Print the old ASCII table of affine automorphisms.
+
+    classes -n <order>
+        Print one latin square per isomorphism class together with the size of its class, the orders of
+        its multiplication groups, its nuclei, commutant and center, and the classes of its principal
+        loop isotopes.
+
+    complete <file> [--count] [--boxes <rows>x<columns>]
+        Complete the partial latin square in the file, with one row per line and \".\" for the empty cells.
+        Prints one completion, or the number of completions with --count. With --boxes the file is
+        completed as a Sudoku with boxes of that size.
+
+    count -n <order>
+        Count the reduced latin squares and all latin squares of order n.
+
+    experiment <name> -n <order>
+        Run one of the experiments in obsolete::experiments. Use \"experiment --list\" to list them.
+
+    help
+        Print this message.";
+
+// The kinds of tables that can be created from the classifications.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableKind {
+    Complete,
+    Summary,
+    Isotopy,
+    MainClass,
+    Mols,
+}
+
+impl TableKind {
+    fn parse(text: &str) -> Result<TableKind, CliError> {
+        match text {
+            "complete" => Ok(TableKind::Complete),
+            "summary" => Ok(TableKind::Summary),
+            "isotopy" => Ok(TableKind::Isotopy),
+            "main-class" => Ok(TableKind::MainClass),
+            "mols" => Ok(TableKind::Mols),
+            _ => Err(CliError::InvalidValue("table kind", text.to_string())),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TableKind::Complete => "complete",
+            TableKind::Summary => "summary",
+            TableKind::Isotopy => "isotopy",
+            TableKind::MainClass => "main-class",
+            TableKind::Mols => "mols",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Spreadsheet {
+        kind: TableKind,
+        n: usize,
+        boxes: Option<(usize, usize)>,
+        output: Option<PathBuf>,
+        threads: Option<usize>,
+    },
+    Latex {
+        kind: TableKind,
+        n: usize,
+        boxes: Option<(usize, usize)>,
+        output: Option<PathBuf>,
+        threads: Option<usize>,
+    },
+    Validate {
+        n: usize,
+        threads: Option<usize>,
+    },
+    Ascii {
+        n: usize,
+    },
+    Classes {
+        n: usize,
+    },
+    Complete {
+        input: PathBuf,
+        count: bool,
+        boxes: Option<(usize, usize)>,
+    },
+    Count {
+        n: usize,
+    },
+    Experiment {
+        name: String,
+        n: usize,
+    },
+    ListExperiments,
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownOption(String),
+    MissingValue(String),
+    MissingOption(&'static str),
+    InvalidValue(&'static str, String),
+    UnknownExperiment(String),
+    InvalidInput(String),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CliError::MissingCommand => write!(f, "No command given."),
+            CliError::UnknownCommand(c) => write!(f, "Unknown command \"{}\".", c),
+            CliError::UnknownOption(o) => write!(f, "Unknown option \"{}\".", o),
+            CliError::MissingValue(o) => write!(f, "Option \"{}\" needs a value.", o),
+            CliError::MissingOption(o) => write!(f, "Missing option \"{}\".", o),
+            CliError::InvalidValue(what, v) => write!(f, "Invalid {} \"{}\".", what, v),
+            CliError::UnknownExperiment(e) => write!(f, "Unknown experiment \"{}\".", e),
+            CliError::InvalidInput(e) => write!(f, "Invalid input: {}", e),
+        }
+    }
+}
+
+// The options shared by the different commands. Which of them are required depends on the command.
+#[derive(Default)]
+struct Options {
+    kind: Option<TableKind>,
+    n: Option<usize>,
+    output: Option<PathBuf>,
+    threads: Option<usize>,
+    boxes: Option<(usize, usize)>,
+    list: bool,
+    count: bool,
+    positional: Vec<String>,
+    // The options as they were given, to reject the ones the command does not use.
+    given: Vec<String>,
+}
+
+// A box size like "2x3", for boxes with 2 rows and 3 columns.
+fn parse_boxes(text: &str) -> Result<(usize, usize), CliError> {
+    let invalid = || CliError::InvalidValue("box size", text.to_string());
+
+    let (rows, columns) = text.split_once('x').ok_or_else(invalid)?;
+    let rows = rows.parse::<usize>().map_err(|_| invalid())?;
+    let columns = columns.parse::<usize>().map_err(|_| invalid())?;
+
+    if rows == 0 || columns == 0 {
+        return Err(invalid());
+    }
+
+    Ok((rows, columns))
+}
+
+// The boxes must fill an n by n grid. The other tables are built from isomorphism classes, which do not keep
+// the boxes.
+fn check_boxes(
+    boxes: Option<(usize, usize)>,
+    n: usize,
+    kind: &TableKind,
+) -> Result<Option<(usize, usize)>, CliError> {
+    if let Some((rows, columns)) = boxes {
+        if rows * columns != n {
+            return Err(CliError::InvalidValue(
+                "box size",
+                format!("{}x{}", rows, columns),
+            ));
+        }
+        if !matches!(kind, TableKind::Complete | TableKind::Summary) {
+            return Err(CliError::InvalidValue(
+                "table kind with --boxes",
+                kind.name().to_string(),
+            ));
+        }
+    }
+
+    Ok(boxes)
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, CliError> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        if arg.starts_with('-') {
+            options.given.push(arg.clone());
+        }
+
+        match arg.as_str() {
+            "--kind" | "--table" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                options.kind = Some(TableKind::parse(&value)?);
+            }
+            "-n" | "--order" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                let n = value
+                    .parse::<usize>()
+                    .map_err(|_| CliError::InvalidValue("order", value.clone()))?;
+                if n == 0 {
+                    return Err(CliError::InvalidValue("order", value));
+                }
+                options.n = Some(n);
+            }
+            "-o" | "--output" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                options.output = Some(PathBuf::from(value));
+            }
+            "--threads" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                let threads = value
+                    .parse::<usize>()
+                    .map_err(|_| CliError::InvalidValue("thread count", value.clone()))?;
+                if threads == 0 {
+                    return Err(CliError::InvalidValue("thread count", value));
+                }
+                options.threads = Some(threads);
+            }
+            "--boxes" => {
+                let value = args.next().ok_or(CliError::MissingValue(arg))?;
+                options.boxes = Some(parse_boxes(&value)?);
+            }
+            "--list" => options.list = true,
+            "--count" => options.count = true,
+            _ if arg.starts_with('-') => return Err(CliError::UnknownOption(arg)),
+            _ => options.positional.push(arg),
+        }
+    }
+
+    Ok(options)
+}
+
+// Parses the command line arguments, not including the name of the binary.
+pub fn parse_arguments(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let command = args.next().ok_or(CliError::MissingCommand)?;
+
+    if matches!(command.as_str(), "help" | "--help" | "-h") {
+        return Ok(Command::Help);
+    }
+
+    let options = parse_options(args)?;
+
+    // The options every command uses. Only experiment and complete take a positional argument, the name or the
+    // file, and only one.
+    let (allowed, positional): (&[&str], usize) = match command.as_str() {
+        "spreadsheet" | "latex" => (
+            &[
+                "--kind",
+                "--table",
+                "-n",
+                "--order",
+                "-o",
+                "--output",
+                "--threads",
+                "--boxes",
+            ],
+            0,
+        ),
+        "validate" => (&["-n", "--order", "--threads"], 0),
+        "ascii" | "classes" | "count" => (&["-n", "--order"], 0),
+        "complete" => (&["--count", "--boxes"], 1),
+        "experiment" => (&["-n", "--order", "--list"], 1),
+        _ => return Err(CliError::UnknownCommand(command)),
+    };
+
+    if let Some(unused) = options
+        .given
+        .iter()
+        .find(|o| !allowed.contains(&o.as_str()))
+    {
+        return Err(CliError::UnknownOption(unused.clone()));
+    }
+
+    if let Some(extra) = options.positional.get(positional) {
+        return Err(CliError::UnknownOption(extra.clone()));
+    }
+
+    match command.as_str() {
+        "spreadsheet" => {
+            let kind = options.kind.ok_or(CliError::MissingOption("--kind"))?;
+            let n = options.n.ok_or(CliError::MissingOption("-n"))?;
+
+            Ok(Command::Spreadsheet {
+                boxes: check_boxes(options.boxes, n, &kind)?,
+                kind,
+                n,
+                output: options.output,
+                threads: options.threads,
+            })
+        }
+        "latex" => {
+            let kind = options.kind.ok_or(CliError::MissingOption("--table"))?;
+            let n = options.n.ok_or(CliError::MissingOption("-n"))?;
+
+            Ok(Command::Latex {
+                boxes: check_boxes(options.boxes, n, &kind)?,
+                kind,
+                n,
+                output: options.output,
+                threads: options.threads,
+            })
+        }
+        "validate" => Ok(Command::Validate {
+            n: options.n.ok_or(CliError::MissingOption("-n"))?,
+            threads: options.threads,
+        }),
+        "ascii" => Ok(Command::Ascii {
+            n: options.n.ok_or(CliError::MissingOption("-n"))?,
+        }),
+        "classes" => Ok(Command::Classes {
+            n: options.n.ok_or(CliError::MissingOption("-n"))?,
+        }),
+        "complete" => {
+            let input = options
+                .positional
+                .first()
+                .ok_or(CliError::MissingOption("<file>"))?;
+
+            Ok(Command::Complete {
+                input: PathBuf::from(input),
+                count: options.count,
+                boxes: options.boxes,
+            })
+        }
+        "count" => Ok(Command::Count {
+            n: options.n.ok_or(CliError::MissingOption("-n"))?,
+        }),
+        "experiment" => {
+            if options.list {
+                return Ok(Command::ListExperiments);
+            }
+
+            let name = options
+                .positional
+                .first()
+                .ok_or(CliError::MissingOption("<name>"))?
+                .clone();
+
+            Ok(Command::Experiment {
+                name,
+                n: options.n.ok_or(CliError::MissingOption("-n"))?,
+            })
+        }
+        _ => Err(CliError::UnknownCommand(command)),
+    }
+}
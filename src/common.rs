@@ -1,3 +1,7 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use indicatif::ProgressBar;
+
 pub fn factorial(n: usize) -> usize {
     let mut result = 1;
 
@@ -7,3 +11,89 @@ pub fn factorial(n: usize) -> usize {
 
     result
 }
+
+// n!, or None if it does not fit in a usize.
+pub fn checked_factorial(n: usize) -> Option<usize> {
+    (1..=n).try_fold(1, |result: usize, i| result.checked_mul(i))
+}
+
+// The number of threads to use when none is given.
+pub fn default_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+// Maps f over the items on several threads, and returns the results in the same order as the items.
+// The progress bar is incremented once per item.
+pub fn parallel_map<T, R, F>(items: &[T], threads: usize, bar: &ProgressBar, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(usize, &T) -> R + Sync,
+{
+    let mut results: Vec<(usize, R)> =
+        parallel_fold(items, threads, bar, Vec::new, |done, i, item| {
+            done.push((i, f(i, item)))
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+
+    results.sort_by_key(|(i, _)| *i);
+
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+// Folds the items into one accumulator per thread, made by init, and returns the accumulators, so that nothing
+// but them has to be kept in memory. The threads take small chunks of items from a shared counter, so slow items
+// do not stall a whole thread's share, which also means the items a thread gets are not in any particular order.
+// The progress bar is incremented once per item.
+pub fn parallel_fold<T, A, I, F>(
+    items: &[T],
+    threads: usize,
+    bar: &ProgressBar,
+    init: I,
+    f: F,
+) -> Vec<A>
+where
+    T: Sync,
+    A: Send,
+    I: Fn() -> A + Sync,
+    F: Fn(&mut A, usize, &T) + Sync,
+{
+    const CHUNK_SIZE: usize = 64;
+
+    let next = AtomicUsize::new(0);
+
+    let (init, f, next) = (&init, &f, &next);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads.max(1))
+            .map(|_| {
+                scope.spawn(move || {
+                    let mut accumulator = init();
+
+                    loop {
+                        let start = next.fetch_add(CHUNK_SIZE, Ordering::Relaxed);
+
+                        if start >= items.len() {
+                            break;
+                        }
+
+                        let end = (start + CHUNK_SIZE).min(items.len());
+
+                        for (i, item) in items[start..end].iter().enumerate() {
+                            f(&mut accumulator, start + i, item);
+                            bar.inc(1);
+                        }
+                    }
+
+                    accumulator
+                })
+            })
+            .collect();
+
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
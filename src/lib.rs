@@ -0,0 +1,19 @@
+#![allow(dead_code)]
+
+// The library part of the crate. The binary in "main.rs" is only a consumer of this,
+// so other tools can depend on the same latin square code.
+
+pub mod common;
+
+pub mod obsolete;
+
+pub mod spreadsheet;
+
+pub mod structs;
+
+// The stable public API.
+pub use structs::latin_square::classify_all_latin_squares;
+pub use structs::traits::{LaTeX, SpreadsheetDisplay};
+pub use structs::PermutationGroup;
+pub use structs::{LatinSquare, LatinSquareClassification, PartialLatinSquare, Permutation};
+pub use structs::{SquareInformation, Table};
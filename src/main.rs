@@ -1,67 +1,169 @@
-#![allow(dead_code)]
-
-mod obsolete;
-
-mod structs;
-use structs::*;
-use traits::LaTeX;
-
-mod common;
-
-mod spreadsheet;
-
-fn create_complete_spreadsheet(n: usize) {
-    // Generate all the n by n latin squares.
-    let squares = LatinSquare::generate_all(n); // [..1000].to_vec();
+use std::path::Path;
+
+use simula_matrix_framework_rust::structs::{
+    latin_square, mols, table, validation, IsomorphismClass, PartialLatinSquareError, Regions,
+};
+use simula_matrix_framework_rust::PartialLatinSquare;
+use simula_matrix_framework_rust::{common, obsolete, spreadsheet};
+use simula_matrix_framework_rust::{LaTeX, LatinSquare, LatinSquareClassification, Permutation};
+use simula_matrix_framework_rust::{SquareInformation, Table};
+
+mod cli;
+use cli::{CliError, Command, TableKind};
+
+fn create_table(
+    n: usize,
+    kind: &TableKind,
+    boxes: Option<(usize, usize)>,
+    threads: usize,
+) -> Table<SquareInformation> {
+    if let Some((rows, columns)) = boxes {
+        return create_sudoku_table(rows, columns, kind == &TableKind::Complete, threads);
+    }
+
+    // Generate one n by n latin square per isomorphism class. A set of MOLS is constructed directly instead.
+    let classes = || LatinSquare::isomorphism_classes(n);
+
+    match kind {
+        TableKind::Complete | TableKind::Summary => {
+            create_classification_table(n, &classes(), kind == &TableKind::Complete, threads)
+        }
+        TableKind::Isotopy => {
+            let summaries = latin_square::summarize_isotopy_classes(&classes(), threads);
+            table::create_class_table(&summaries)
+        }
+        TableKind::MainClass => {
+            let summaries = latin_square::summarize_main_classes(&classes(), threads);
+            table::create_class_table(&summaries)
+        }
+        TableKind::Mols => table::create_mols_table(&mols::mols(n)),
+    }
+}
 
+// The complete or summary table.
+fn create_classification_table(
+    n: usize,
+    classes: &[IsomorphismClass],
+    complete: bool,
+    threads: usize,
+) -> Table<SquareInformation> {
     // Generate all the permutations on n elements.
     let perms = Permutation::generate_all(n);
 
-    // Calculate the classifications (AAuts, Auts, Class, etc... for every latin square)
-    let classification: Vec<LatinSquareClassification> =
-        structs::latin_square::classify_all_latin_squares(&squares, &perms);
-
-    // Turn the classifications into the table format that can be exported.
-    let table: Table<SquareInformation> = table::create_complete_table(classification, &perms);
-
-    // Export table as a spreadsheet,
-    spreadsheet::write_table_to_spreadsheet(&table, n, "complete").unwrap();
+    // Calculate the classifications (AAuts, Auts, Class, etc... for every latin square),
+    // and turn them into the table format that can be exported.
+    if complete {
+        let classification: Vec<LatinSquareClassification> = if threads > 1 {
+            latin_square::classify_all_isomorphism_classes_parallel(classes, &perms, threads)
+        } else {
+            latin_square::classify_all_isomorphism_classes(classes, &perms)
+        };
+
+        table::create_complete_table(classification, &perms)
+    } else {
+        // The summary does not depend on the order of the squares, so only one square per class is classified.
+        let sums = if threads > 1 {
+            latin_square::summarize_fingerprints_parallel(classes, &perms, threads)
+        } else {
+            latin_square::summarize_fingerprints(classes, &perms)
+        };
+
+        table::create_summary_table_from_sums(sums, &perms)
+    }
 }
 
-fn create_summary_spreadsheet(n: usize) {
-    // Generate all the n by n latin squares.
-    let squares = LatinSquare::generate_all(n); // [..1000].to_vec();
-
-    // Generate all the permutations on n elements.
-    let perms = Permutation::generate_all(n);
-
-    // Calculate the classifications (AAuts, Auts, Class, etc... for every latin square)
-    let classification: Vec<LatinSquareClassification> =
-        structs::latin_square::classify_all_latin_squares(&squares, &perms);
+// The complete or summary table of the Sudokus with the given boxes. The isomorphism classes do not keep the
+// boxes, so every Sudoku is classified on its own.
+fn create_sudoku_table(
+    rows: usize,
+    columns: usize,
+    complete: bool,
+    threads: usize,
+) -> Table<SquareInformation> {
+    let squares: Vec<LatinSquare> = Regions::sudoku(rows, columns)
+        .designs()
+        .into_iter()
+        .map(|d| d.square)
+        .collect();
+
+    let perms = Permutation::generate_all(rows * columns);
+
+    let classification: Vec<LatinSquareClassification> = if threads > 1 {
+        latin_square::classify_all_latin_squares_parallel(&squares, &perms, threads)
+    } else {
+        latin_square::classify_all_latin_squares(&squares, &perms)
+    };
+
+    if complete {
+        table::create_complete_table(classification, &perms)
+    } else {
+        table::create_summary_table(classification, &perms)
+    }
+}
 
-    // Turn the classifications into the table format that can be exported.
-    let table = table::create_summary_table(classification, &perms);
+fn create_spreadsheet(
+    n: usize,
+    kind: &TableKind,
+    boxes: Option<(usize, usize)>,
+    output: Option<&Path>,
+    threads: usize,
+) -> Result<(), CliError> {
+    let table = create_table(n, kind, boxes, threads);
+
+    let name = match boxes {
+        Some((rows, columns)) => format!("sudoku_{}x{}_{}", rows, columns, kind.name()),
+        None => kind.name().to_string(),
+    };
+
+    let path = match output {
+        Some(path) => path.to_path_buf(),
+        None => spreadsheet::default_spreadsheet_path(n, &name),
+    };
 
     // Export table as a spreadsheet,
-    spreadsheet::write_table_to_spreadsheet(&table, n, "summary").unwrap();
+    spreadsheet::write_table_to_spreadsheet(&table, &path)
+        .map_err(|e| CliError::InvalidInput(e.to_string()))
 }
 
-fn create_latex_table(n: usize) {
-    // Generate all the n by n latin squares.
-    let squares = LatinSquare::generate_all(n); // [..1000].to_vec();
+fn create_latex_table(
+    n: usize,
+    kind: &TableKind,
+    boxes: Option<(usize, usize)>,
+    output: Option<&Path>,
+    threads: usize,
+) -> Result<(), CliError> {
+    let table = create_table(n, kind, boxes, threads);
+
+    match output {
+        Some(path) => std::fs::write(path, table.latex())
+            .map_err(|e| CliError::InvalidInput(e.to_string()))?,
+        None => println!("{}", table.latex()),
+    }
+
+    Ok(())
+}
 
-    // Generate all the permutations on n elements.
+// Prints every violation and returns whether there were none.
+fn validate(n: usize, threads: usize) -> bool {
+    let classes = LatinSquare::isomorphism_classes(n);
     let perms = Permutation::generate_all(n);
 
-    // Calculate the classifications (AAuts, Auts, Class, etc... for every latin square)
     let classification: Vec<LatinSquareClassification> =
-        structs::latin_square::classify_all_latin_squares(&squares, &perms);
+        latin_square::classify_all_isomorphism_classes_parallel(&classes, &perms, threads);
+
+    let errors = validation::validate_classifications(&classification, &perms);
 
-    // Turn the classifications into the table format that can be exported.
-    let table = table::create_summary_table(classification, &perms);
-    // let table: Table<SquareInformation> = table::create_complete_table(classification, &perms);
+    for e in errors.iter() {
+        println!("{}", e);
+    }
 
-    println!("{}", table.latex())
+    println!(
+        "Latin squares: {}, Violations: {}",
+        classification.len(),
+        errors.len()
+    );
+
+    errors.is_empty()
 }
 
 fn create_ascii_table(n: usize) {
@@ -75,27 +177,153 @@ fn create_ascii_table(n: usize) {
     obsolete::affine_automorphism_table::print_affine_automorphism_table(&squares, &perms);
 }
 
-// TODO: Methods vs standalone functions.
-fn main() {
+fn print_isomorphism_classes(n: usize) {
+    let classes = LatinSquare::isomorphism_classes(n);
+    let loop_isotopes = latin_square::principal_loop_isotope_classes(&classes);
 
-    // Create the summary spreadsheet for 5x5 latin squares.
-    // create_summary_spreadsheet(5);
+    for (i, (class, loops)) in classes.iter().zip(loop_isotopes).enumerate() {
+        let loops: Vec<String> = loops.iter().map(|l| format!("C_{}", l)).collect();
 
-    // Create the complete spreadsheet for 4x4 latin squares.
-    // create_complete_spreadsheet(4);
+        println!("C_{}", i);
+        println!("{}", class.representative);
+        println!("{}", class.representative.multiplication_report());
+        println!("Principal loop isotopes: {}", loops.join(", "));
+        println!("Orbit size: {}\n", class.orbit_size);
+    }
 
-    // Print the latex table for 4x4 latin squares (comment and uncomment type of table needed in the function above).
-    // create_latex_table(4);
+    let total: usize = classes.iter().map(|c| c.orbit_size).sum();
 
-    // Creates the ASCII table for 3x3 latin squares.
-    // create_ascii_table(3);
+    println!(
+        "Isomorphism classes: {}, Latin squares: {}",
+        classes.len(),
+        total
+    );
+}
 
-    // #############################
+fn complete_partial_latin_square(
+    input: &Path,
+    count: bool,
+    boxes: Option<(usize, usize)>,
+) -> Result<(), CliError> {
+    let text = std::fs::read_to_string(input).map_err(|e| CliError::InvalidInput(e.to_string()))?;
+    let partial: PartialLatinSquare = text
+        .parse()
+        .map_err(|e: PartialLatinSquareError| CliError::InvalidInput(e.to_string()))?;
+
+    let regions = match boxes {
+        Some((rows, columns)) if rows * columns != partial.order() => {
+            return Err(CliError::InvalidValue(
+                "box size",
+                format!("{}x{}", rows, columns),
+            ))
+        }
+        Some((rows, columns)) => Some(Regions::sudoku(rows, columns)),
+        None => None,
+    };
+
+    if count {
+        let completions = match &regions {
+            Some(regions) => regions.count_completions(&partial),
+            None => partial.count_completions(),
+        };
+
+        println!("Completions: {}", completions);
+        return Ok(());
+    }
+
+    let completion: Option<String> = match &regions {
+        Some(regions) => regions.complete(&partial).map(|d| d.to_string()),
+        None => partial.complete().map(|s| s.to_string()),
+    };
+
+    match completion {
+        Some(text) => println!("{}", text),
+        None => println!("No completion."),
+    }
+
+    Ok(())
+}
+
+fn print_latin_square_count(n: usize) {
+    let reduced = LatinSquare::count_reduced(n);
+
+    println!("Reduced latin squares R({}): {}", n, reduced);
+
+    match LatinSquare::count_all_from_reduced(n, reduced) {
+        Some(all) => println!("Latin squares L({}): {}", n, all),
+        None => println!("Latin squares L({}): too large for a usize", n),
+    }
+}
 
-    // Run an experiment.
-    // obsolete::experiments::{abelian, latin_square}::{name of experiment}
-    // Example:
-    // obsolete::experiments::abelian::try_permutation_gives_automorphism(5);
+fn run(command: Command) -> Result<(), CliError> {
+    match command {
+        Command::Spreadsheet {
+            kind,
+            n,
+            boxes,
+            output,
+            threads,
+        } => create_spreadsheet(
+            n,
+            &kind,
+            boxes,
+            output.as_deref(),
+            threads.unwrap_or_else(common::default_threads),
+        )?,
+        Command::Latex {
+            kind,
+            n,
+            boxes,
+            output,
+            threads,
+        } => create_latex_table(
+            n,
+            &kind,
+            boxes,
+            output.as_deref(),
+            threads.unwrap_or_else(common::default_threads),
+        )?,
+        Command::Validate { n, threads } => {
+            if !validate(n, threads.unwrap_or_else(common::default_threads)) {
+                std::process::exit(1);
+            }
+        }
+        Command::Ascii { n } => create_ascii_table(n),
+        Command::Classes { n } => print_isomorphism_classes(n),
+        Command::Complete {
+            input,
+            count,
+            boxes,
+        } => complete_partial_latin_square(&input, count, boxes)?,
+        Command::Count { n } => print_latin_square_count(n),
+        Command::Experiment { name, n } => {
+            let experiment = obsolete::experiments::find_experiment(&name)
+                .ok_or(CliError::UnknownExperiment(name))?;
+            experiment(n)
+        }
+        Command::ListExperiments => {
+            for (name, _) in obsolete::experiments::EXPERIMENTS {
+                println!("{}", name);
+            }
+        }
+        Command::Help => println!("{}", cli::USAGE),
+    }
+
+    Ok(())
+}
+
+// TODO: Methods vs standalone functions.
+fn main() {
+    // Examples:
+    // cargo run -r -- spreadsheet --kind summary -n 5
+    // cargo run -r -- latex --table complete -n 4 --output table.tex
+    // cargo run -r -- experiment try_permutation_gives_automorphism -n 5
+    let result = cli::parse_arguments(std::env::args().skip(1)).and_then(run);
+
+    if let Err(e) = result {
+        eprintln!("{}\n\n{}", e, cli::USAGE);
+        std::process::exit(2);
+    }
 
     // #############################
 
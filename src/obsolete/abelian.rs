@@ -127,7 +127,7 @@ fn check_associativity_advanced<'a>(
 
 // TODO: Overlapping code at the bottom
 fn group_generation_recursion_new(
-    table: &Vec<Vec<usize>>,
+    table: &[Vec<usize>],
     n: usize,
     remaining_associatvity_checks: &[[usize; 3]],
 ) -> Vec<Vec<Vec<usize>>> {
@@ -179,7 +179,7 @@ fn group_generation_recursion_new(
                         continue 'val;
                     }
                 }
-                let mut working_table = table.clone();
+                let mut working_table = table.to_vec();
                 working_table.last_mut().unwrap().push(i);
 
                 if let Some(remaining_checks) =
@@ -59,13 +59,13 @@ fn is_group_associative(table: &[Vec<usize>]) -> bool {
 
 // Assume table is already a valid and non-empty table for the given values
 // TODO: Check associativity while running, not only at the end.
-fn group_generation_recursion(table: &Vec<Vec<usize>>, n: usize) -> Vec<Vec<Vec<usize>>> {
+fn group_generation_recursion(table: &[Vec<usize>], n: usize) -> Vec<Vec<Vec<usize>>> {
     let mut result: Vec<Vec<Vec<usize>>> = vec![];
 
     if let Some(last_row) = table.last() {
         if table.len() == n {
             if is_group_associative(table) {
-                return vec![table.clone()];
+                return vec![table.to_vec()];
             }
             return vec![];
         }
@@ -90,7 +90,7 @@ fn group_generation_recursion(table: &Vec<Vec<usize>>, n: usize) -> Vec<Vec<Vec<
                         continue 'val;
                     }
                 }
-                let mut working_table = table.clone();
+                let mut working_table = table.to_vec();
                 working_table.push(vec![i]);
                 result.append(&mut group_generation_recursion(&working_table, n))
             }
@@ -104,7 +104,7 @@ fn group_generation_recursion(table: &Vec<Vec<usize>>, n: usize) -> Vec<Vec<Vec<
                         continue 'val;
                     }
                 }
-                let mut working_table = table.clone();
+                let mut working_table = table.to_vec();
                 working_table.last_mut().unwrap().push(i);
                 result.append(&mut group_generation_recursion(&working_table, n))
             }
@@ -1,3 +1,41 @@
 pub mod abelian;
 pub mod affine_automorphism;
 pub mod latin_square;
+
+// An experiment that only takes the order n as input, together with the name used on the command line.
+pub type Experiment = (&'static str, fn(usize));
+
+pub const EXPERIMENTS: [Experiment; 6] = [
+    (
+        "try_permutation_gives_automorphism",
+        abelian::try_permutation_gives_automorphism,
+    ),
+    (
+        "try_permutation_is_group_op",
+        abelian::try_permutation_is_group_op,
+    ),
+    (
+        "try_exist_perm_for_every_group_gives_automorphism",
+        abelian::try_exist_perm_for_every_group_gives_automorphism,
+    ),
+    (
+        "try_permutation_affine_automorphism",
+        abelian::try_permutation_affine_automorphism,
+    ),
+    (
+        "try_permutations_equal_in_isomorphism_class",
+        abelian::try_permutations_equal_in_isomorphism_class,
+    ),
+    (
+        "try_class_preserved_after_conjugacy",
+        latin_square::try_class_preserved_after_conjugacy,
+    ),
+];
+
+// Finds an experiment by name.
+pub fn find_experiment(name: &str) -> Option<fn(usize)> {
+    EXPERIMENTS
+        .iter()
+        .find(|(experiment, _)| *experiment == name)
+        .map(|(_, f)| *f)
+}
@@ -1,7 +1,7 @@
 use indicatif::ProgressBar;
 
 use crate::common::factorial;
-use crate::latin_square::*;
+use crate::structs::LatinSquare;
 
 use crate::structs::LatinStructure;
 use crate::structs::Permutation;
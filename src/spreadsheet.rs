@@ -1,3 +1,6 @@
+use std::path::Path;
+use std::path::PathBuf;
+
 use color::Rgb;
 use icu_locid::locale;
 use spreadsheet_ods::style::CellStyle;
@@ -8,10 +11,20 @@ use spreadsheet_ods::ValueFormatText;
 use crate::structs::traits::SpreadsheetDisplay;
 use crate::structs::{SpreadsheetColours, Table};
 
+// The default location of a spreadsheet, e.g. "spreadsheets/summary_5.ods".
+pub fn default_spreadsheet_path(n: usize, name: &str) -> PathBuf {
+    let mut path = "spreadsheets/".to_string();
+    path.push_str(name);
+    path.push('_');
+    path.push_str(&n.to_string());
+    path.push_str(".ods");
+
+    PathBuf::from(path)
+}
+
 pub fn write_table_to_spreadsheet<T>(
     table: &Table<T>,
-    n: usize,
-    name: &str,
+    path: &Path,
 ) -> Result<(), spreadsheet_ods::OdsError>
 where
     T: SpreadsheetDisplay,
@@ -88,11 +101,5 @@ where
         }
     }
 
-    let mut path = "spreadsheets/".to_string();
-    path.push_str(name);
-    path.push('_');
-    path.push_str(&n.to_string());
-    path.push_str(".ods");
-
     spreadsheet_ods::write_ods(&mut wb, path)
 }
@@ -3,22 +3,68 @@ pub mod traits;
 pub mod latin_square;
 pub use latin_square::LatinSquare;
 
+mod exact_cover;
+pub use exact_cover::ExactCover;
+
+mod latin_square_cover;
+pub use latin_square_cover::LatinSquareCover;
+
+mod gerechte_design;
+pub use gerechte_design::GerechteDesign;
+pub use gerechte_design::GerechteError;
+pub use gerechte_design::Regions;
+
+pub mod finite_field;
+pub use finite_field::FiniteField;
+
+pub mod mols;
+pub use mols::MolsError;
+
+mod partial_latin_square;
+pub use partial_latin_square::PartialLatinSquare;
+pub use partial_latin_square::PartialLatinSquareError;
+
 pub mod permutation;
+pub use permutation::CycleNotationError;
 pub use permutation::Permutation;
 
+mod permutation_group;
+pub use permutation_group::PermutationGroup;
+
 mod bits;
 pub use bits::Bits;
 
 mod latin_type;
 pub use latin_type::LatinType;
 
+mod quasigroup_properties;
+pub use quasigroup_properties::LoopProperties;
+pub use quasigroup_properties::QuasigroupProperties;
+
 pub mod table;
+pub use table::SummarySums;
 pub use table::Table;
 
+mod parastrophy;
+pub use parastrophy::Parastrophy;
+pub use parastrophy::PARASTROPHIES;
+
+mod affine_map;
+pub use affine_map::AffineMap;
+
 mod sidedness;
 pub use sidedness::Sidedness;
 pub use sidedness::SIDES;
 
+mod isomorphism_class;
+pub use isomorphism_class::IsomorphismClass;
+
+mod multiplication_report;
+pub use multiplication_report::MultiplicationReport;
+
+mod class_summary;
+pub use class_summary::ClassSummary;
+
 mod latin_square_classification;
 pub use latin_square_classification::LatinSquareClassification;
 
@@ -28,8 +74,13 @@ pub use latin_structure::LatinStructure;
 mod square_information;
 pub use square_information::SquareInformation;
 
+pub mod validation;
+pub use validation::ValidationError;
+
 mod spreadsheet_colors;
 pub use spreadsheet_colors::SpreadsheetColours;
 
 type AffineAutomorphism = (usize, usize, Sidedness);
 pub type AllAffineAutomorphisms = (bool, Vec<AffineAutomorphism>);
+// Permutations of the rows, columns and symbols.
+pub type Isotopism = (Permutation, Permutation, Permutation);
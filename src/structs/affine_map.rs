@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use super::LatinSquare;
+use super::Permutation;
+
+// The map x -> (a + p(x)) + b on the elements of a latin square, where + is the operation of the square and p is
+// an automorphism. Either translation can be left out, which gives the one-sided forms v + p(x) and p(x) + v.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AffineMap {
+    pub left: Option<usize>,
+    pub automorphism: Permutation,
+    pub right: Option<usize>,
+}
+
+impl AffineMap {
+    pub fn apply(&self, square: &LatinSquare, x: usize) -> usize {
+        let mut y = self.automorphism.0[x];
+
+        if let Some(a) = self.left {
+            y = square.0[a][y];
+        }
+        if let Some(b) = self.right {
+            y = square.0[y][b];
+        }
+
+        y
+    }
+
+    // The map as a permutation of the elements.
+    pub fn permutation(&self, square: &LatinSquare) -> Permutation {
+        Permutation((0..square.0.len()).map(|x| self.apply(square, x)).collect())
+    }
+
+    // Finds an affine map equal to the permutation f, if there is one. Given a and b, the automorphism has to be
+    // p(x) = a \ (f(x) / b), so only the translations are searched. The first one found is returned, trying the
+    // forms without translations first.
+    pub fn from_permutation(square: &LatinSquare, f: &Permutation) -> Option<AffineMap> {
+        let n = square.0.len();
+
+        let translations: Vec<Option<usize>> =
+            std::iter::once(None).chain((0..n).map(Some)).collect();
+
+        for left in translations.iter() {
+            for right in translations.iter() {
+                let automorphism = Permutation(
+                    (0..n)
+                        .map(|x| {
+                            let mut y = f.0[x];
+
+                            if let Some(b) = right {
+                                y = (0..n).find(|z| square.0[*z][*b] == y).unwrap();
+                            }
+                            if let Some(a) = left {
+                                y = square.0[*a].iter().position(|z| *z == y).unwrap();
+                            }
+
+                            y
+                        })
+                        .collect(),
+                );
+
+                if square.is_automorphism(&automorphism) {
+                    return Some(AffineMap {
+                        left: *left,
+                        automorphism,
+                        right: *right,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    // The map x -> self(inner(x)), if it is an affine map. This is always the case for groups.
+    pub fn compose(&self, inner: &AffineMap, square: &LatinSquare) -> Option<AffineMap> {
+        let f = self.permutation(square).compose(&inner.permutation(square));
+
+        AffineMap::from_permutation(square, &f)
+    }
+
+    // The inverse map, if it is an affine map. This is always the case for groups.
+    pub fn inverse(&self, square: &LatinSquare) -> Option<AffineMap> {
+        AffineMap::from_permutation(square, &self.permutation(square).inverse())
+    }
+}
+
+// For example "2 + (0 1)(3 4) + 1", where the automorphism is in cycle notation.
+impl Display for AffineMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut text: String = "".to_string();
+
+        if let Some(a) = self.left {
+            text.push_str(&a.to_string());
+            text.push_str(" + ");
+        }
+
+        text.push_str(&self.automorphism.cycle_notation());
+
+        if let Some(b) = self.right {
+            text.push_str(" + ");
+            text.push_str(&b.to_string());
+        }
+
+        write!(f, "{}", text)
+    }
+}
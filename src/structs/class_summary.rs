@@ -0,0 +1,23 @@
+use std::collections::BTreeSet;
+
+use super::LatinSquare;
+
+// An isotopy class or main class of latin squares of some order, summarized from the isomorphism classes in it.
+#[derive(Debug, Clone)]
+pub struct ClassSummary {
+    // The lexicographically smallest square in the class.
+    pub canonical_form: LatinSquare,
+    // The number of labelled latin squares in the class.
+    pub squares: usize,
+    pub isomorphism_classes: usize,
+    // 1 for an isotopy class.
+    pub isotopy_classes: usize,
+    // The isomorphism classes of loops. Every square in the class has a principal loop isotope in each of them.
+    pub loops: usize,
+    // The same for every square in the class.
+    pub autotopisms: usize,
+    pub autoparatopisms: usize,
+    pub transversals: usize,
+    // The different numbers of automorphisms of the squares in the class.
+    pub automorphisms: BTreeSet<usize>,
+}
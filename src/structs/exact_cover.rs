@@ -0,0 +1,240 @@
+// An exact cover problem solved with Knuth's Algorithm X and dancing links. The problem has primary columns,
+// which every solution covers exactly once, and secondary columns, which it covers at most once. The options
+// are the rows, each covering some of the columns, and a solution is a set of rows.
+//
+// The rows and columns are stored as circular doubly linked lists through the nodes, so a column and the rows
+// that conflict with it are removed while searching and put back in reverse order without copying anything.
+// Node 0 is the root, and nodes 1, ..., columns are the column headers, where only the primary columns are
+// linked to the root.
+#[derive(Debug, Clone)]
+pub struct ExactCover {
+    primary: usize,
+    columns: usize,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    // The column header of every node.
+    column: Vec<usize>,
+    // The row of every node, unused for the root and headers.
+    row: Vec<usize>,
+    // The number of rows in every column, indexed by header.
+    size: Vec<usize>,
+    rows: usize,
+}
+
+impl ExactCover {
+    // The primary columns are 0, ..., primary - 1, and the secondary columns primary, ..., primary + secondary - 1.
+    pub fn new(primary: usize, secondary: usize) -> ExactCover {
+        let columns = primary + secondary;
+        let headers = columns + 1;
+
+        let mut cover = ExactCover {
+            primary,
+            columns,
+            left: (0..headers).map(|i| i.saturating_sub(1)).collect(),
+            right: (0..headers).map(|i| i + 1).collect(),
+            up: (0..headers).collect(),
+            down: (0..headers).collect(),
+            column: (0..headers).collect(),
+            row: vec![0; headers],
+            size: vec![0; headers],
+            rows: 0,
+        };
+
+        // Close the list of primary columns, and let every secondary column be a list of its own.
+        cover.left[0] = primary;
+        cover.right[primary] = 0;
+
+        for h in primary + 1..headers {
+            cover.left[h] = h;
+            cover.right[h] = h;
+        }
+
+        cover
+    }
+
+    pub fn primary_columns(&self) -> usize {
+        self.primary
+    }
+
+    pub fn secondary_columns(&self) -> usize {
+        self.columns - self.primary
+    }
+
+    // The number of rows added so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    // Adds a row covering the given columns, which must be different. Returns the index of the row.
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        let r = self.rows;
+        self.rows += 1;
+
+        let first = self.left.len();
+
+        for (k, c) in columns.iter().enumerate() {
+            assert!(*c < self.columns, "Column {} does not exist.", c);
+
+            let h = c + 1;
+            let node = first + k;
+
+            self.column.push(h);
+            self.row.push(r);
+
+            // Insert at the bottom of the column.
+            self.up.push(self.up[h]);
+            self.down.push(h);
+            let last = self.up[h];
+            self.down[last] = node;
+            self.up[h] = node;
+            self.size[h] += 1;
+
+            // Insert at the end of the row.
+            self.left.push(if k == 0 { node } else { node - 1 });
+            self.right.push(first);
+            if k > 0 {
+                self.right[node - 1] = node;
+                self.left[first] = node;
+            }
+        }
+
+        r
+    }
+
+    fn cover(&mut self, h: usize) {
+        self.right[self.left[h]] = self.right[h];
+        self.left[self.right[h]] = self.left[h];
+
+        let mut i = self.down[h];
+        while i != h {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, h: usize) {
+        let mut i = self.up[h];
+        while i != h {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+
+        self.right[self.left[h]] = h;
+        self.left[self.right[h]] = h;
+    }
+
+    // Calls found with the rows of every solution, until found returns false. The rows of a solution are in the
+    // order they were chosen. Every primary column is chosen by fewest remaining rows, the first one on ties.
+    pub fn for_each_solution(&mut self, found: &mut dyn FnMut(&[usize]) -> bool) {
+        let mut solution: Vec<usize> = vec![];
+
+        self.search(&mut solution, found);
+    }
+
+    // Returns false if found asked to stop.
+    fn search(
+        &mut self,
+        solution: &mut Vec<usize>,
+        found: &mut dyn FnMut(&[usize]) -> bool,
+    ) -> bool {
+        if self.right[0] == 0 {
+            return found(solution);
+        }
+
+        let mut h = self.right[0];
+        let mut j = self.right[h];
+        while j != 0 {
+            if self.size[j] < self.size[h] {
+                h = j;
+            }
+            j = self.right[j];
+        }
+
+        if self.size[h] == 0 {
+            return true;
+        }
+
+        self.cover(h);
+
+        let mut go_on = true;
+        let mut i = self.down[h];
+
+        while i != h && go_on {
+            solution.push(self.row[i]);
+
+            let mut j = self.right[i];
+            while j != i {
+                self.cover(self.column[j]);
+                j = self.right[j];
+            }
+
+            go_on = self.search(solution, found);
+
+            let mut j = self.left[i];
+            while j != i {
+                self.uncover(self.column[j]);
+                j = self.left[j];
+            }
+
+            solution.pop();
+            i = self.down[i];
+        }
+
+        self.uncover(h);
+
+        go_on
+    }
+
+    // One solution, or None if there is none.
+    pub fn first_solution(&mut self) -> Option<Vec<usize>> {
+        let mut result: Option<Vec<usize>> = None;
+
+        self.for_each_solution(&mut |rows| {
+            result = Some(rows.to_vec());
+            false
+        });
+
+        result
+    }
+
+    // Every solution, with the rows of each sorted, in sorted order.
+    pub fn solutions(&mut self) -> Vec<Vec<usize>> {
+        let mut result: Vec<Vec<usize>> = vec![];
+
+        self.for_each_solution(&mut |rows| {
+            let mut rows = rows.to_vec();
+            rows.sort();
+            result.push(rows);
+            true
+        });
+
+        result.sort();
+
+        result
+    }
+
+    pub fn count_solutions(&mut self) -> usize {
+        let mut count: usize = 0;
+
+        self.for_each_solution(&mut |_| {
+            count += 1;
+            true
+        });
+
+        count
+    }
+}
@@ -0,0 +1,140 @@
+// The finite field with q = p^k elements. The elements are 0, ..., q - 1, where the base p digits of an element
+// are the coefficients of a polynomial over the integers modulo p, the lowest digit being the constant term.
+// Multiplication is modulo a monic irreducible polynomial of degree k.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FiniteField {
+    pub characteristic: usize,
+    pub degree: usize,
+    add: Vec<Vec<usize>>,
+    mul: Vec<Vec<usize>>,
+}
+
+// (p, k) if n = p^k for a prime p and k >= 1.
+pub fn prime_power(n: usize) -> Option<(usize, usize)> {
+    let p = (2..=n).find(|d| n.is_multiple_of(*d))?;
+
+    let mut rest = n;
+    let mut k = 0;
+
+    while rest.is_multiple_of(p) {
+        rest /= p;
+        k += 1;
+    }
+
+    (rest == 1).then_some((p, k))
+}
+
+// The prime powers whose product is n, in increasing order of the primes.
+pub fn prime_power_factors(mut n: usize) -> Vec<usize> {
+    let mut factors: Vec<usize> = vec![];
+    let mut p = 2;
+
+    while n > 1 {
+        let mut q = 1;
+
+        while n.is_multiple_of(p) {
+            n /= p;
+            q *= p;
+        }
+
+        if q > 1 {
+            factors.push(q);
+        }
+
+        p += 1;
+    }
+
+    factors
+}
+
+impl FiniteField {
+    // The field of order q, or None if q is not a prime power.
+    pub fn new(q: usize) -> Option<FiniteField> {
+        let (p, k) = prime_power(q)?;
+
+        // Try the monic polynomials x^k + c in turn, where c is the lower coefficients, until the quotient ring
+        // has no zero divisors, which happens exactly when the polynomial is irreducible.
+        (0..q).find_map(|c| {
+            let field = FiniteField::with_polynomial(p, k, c);
+
+            let is_field = (1..q).all(|x| (1..q).all(|y| field.mul[x][y] != 0));
+
+            is_field.then_some(field)
+        })
+    }
+
+    // The ring of polynomials modulo x^k + c, where the base p digits of c are the lower coefficients.
+    fn with_polynomial(p: usize, k: usize, c: usize) -> FiniteField {
+        let q = p.pow(k as u32);
+
+        let digits = |x: usize| -> Vec<usize> { (0..k).map(|i| x / p.pow(i as u32) % p).collect() };
+        let number = |d: &[usize]| -> usize { d.iter().rev().fold(0, |x, d| x * p + d) };
+
+        let modulus = digits(c);
+
+        let add: Vec<Vec<usize>> = (0..q)
+            .map(|x| {
+                (0..q)
+                    .map(|y| {
+                        let sum: Vec<usize> = digits(x)
+                            .iter()
+                            .zip(digits(y))
+                            .map(|(a, b)| (a + b) % p)
+                            .collect();
+                        number(&sum)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mul: Vec<Vec<usize>> = (0..q)
+            .map(|x| {
+                (0..q)
+                    .map(|y| {
+                        let (a, b) = (digits(x), digits(y));
+
+                        // The product has degree at most 2k - 2.
+                        let mut product: Vec<usize> = vec![0; 2 * k];
+                        for i in 0..k {
+                            for j in 0..k {
+                                product[i + j] = (product[i + j] + a[i] * b[j]) % p;
+                            }
+                        }
+
+                        // Replace x^k by -c, from the top.
+                        for i in (k..2 * k).rev() {
+                            let t = product[i];
+                            product[i] = 0;
+
+                            for j in 0..k {
+                                product[i - k + j] =
+                                    (product[i - k + j] + (p - modulus[j]) * t) % p;
+                            }
+                        }
+
+                        number(&product[..k])
+                    })
+                    .collect()
+            })
+            .collect();
+
+        FiniteField {
+            characteristic: p,
+            degree: k,
+            add,
+            mul,
+        }
+    }
+
+    pub fn order(&self) -> usize {
+        self.add.len()
+    }
+
+    pub fn add(&self, x: usize, y: usize) -> usize {
+        self.add[x][y]
+    }
+
+    pub fn mul(&self, x: usize, y: usize) -> usize {
+        self.mul[x][y]
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::Display;
+
+use super::LatinSquare;
+use super::LatinSquareCover;
+use super::PartialLatinSquare;
+
+// A partition of the cells of an n by n grid into n regions of n cells each, where regions.0[r][c] is the region
+// of the cell (r, c). A gerechte design is a latin square with every symbol once in every region, and a Sudoku is
+// a gerechte design where the regions are boxes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Regions(pub Vec<Vec<usize>>);
+
+// A latin square together with regions that it has every symbol once in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GerechteDesign {
+    pub square: LatinSquare,
+    pub regions: Regions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GerechteError {
+    // The grid of regions is not square, or not the same size as the latin square.
+    WrongOrder(usize),
+    // A region number that is not less than n.
+    InvalidRegion(usize),
+    // The region and the number of cells in it, when it is not n.
+    WrongRegionSize(usize, usize),
+    // The region and the symbol that appears more than once in it.
+    RepeatedSymbol(usize, usize),
+}
+
+impl Display for GerechteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongOrder(n) => write!(f, "The grid does not have order {}.", n),
+            Self::InvalidRegion(r) => write!(f, "There is no region {}.", r),
+            Self::WrongRegionSize(r, size) => write!(f, "Region {} has {} cells.", r, size),
+            Self::RepeatedSymbol(r, s) => {
+                write!(f, "{} appears more than once in region {}.", s, r)
+            }
+        }
+    }
+}
+
+impl Regions {
+    // Checks that the grid is square and that every region has n cells.
+    pub fn new(regions: Vec<Vec<usize>>) -> Result<Regions, GerechteError> {
+        let n = regions.len();
+
+        if regions.iter().any(|row| row.len() != n) {
+            return Err(GerechteError::WrongOrder(n));
+        }
+
+        let mut sizes: Vec<usize> = vec![0; n];
+
+        for region in regions.iter().flatten() {
+            if *region >= n {
+                return Err(GerechteError::InvalidRegion(*region));
+            }
+
+            sizes[*region] += 1;
+        }
+
+        match sizes.iter().position(|size| *size != n) {
+            Some(r) => Err(GerechteError::WrongRegionSize(r, sizes[r])),
+            None => Ok(Regions(regions)),
+        }
+    }
+
+    // The Sudoku regions of order n = box_rows * box_columns, i.e. boxes with box_rows rows and box_columns
+    // columns, numbered in row-major order.
+    pub fn sudoku(box_rows: usize, box_columns: usize) -> Regions {
+        let n = box_rows * box_columns;
+
+        Regions(
+            (0..n)
+                .map(|r| {
+                    (0..n)
+                        .map(|c| (r / box_rows) * box_rows + c / box_columns)
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn order(&self) -> usize {
+        self.0.len()
+    }
+
+    // The cells of the region in row-major order.
+    pub fn cells(&self, region: usize) -> Vec<(usize, usize)> {
+        let n = self.order();
+
+        (0..n * n)
+            .map(|cell| (cell / n, cell % n))
+            .filter(|(r, c)| self.0[*r][*c] == region)
+            .collect()
+    }
+
+    // Checks that the square has every symbol once in every region. The rows and columns are not checked.
+    pub fn check(&self, square: &LatinSquare) -> Result<(), GerechteError> {
+        let n = self.order();
+
+        if square.0.len() != n {
+            return Err(GerechteError::WrongOrder(n));
+        }
+
+        for region in 0..n {
+            let mut seen: Vec<bool> = vec![false; n];
+
+            for (r, c) in self.cells(region) {
+                let s = square.0[r][c];
+
+                if seen[s] {
+                    return Err(GerechteError::RepeatedSymbol(region, s));
+                }
+
+                seen[s] = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    // The latin squares with every symbol once in every region, as an exact cover problem. More constraints can
+    // be added to it, see LatinSquareCover.
+    pub fn cover(&self) -> LatinSquareCover {
+        let mut cover = LatinSquareCover::new(self.order());
+        self.add_regions(&mut cover);
+
+        cover
+    }
+
+    fn add_regions(&self, cover: &mut LatinSquareCover) {
+        let n = self.order();
+
+        for region in 0..n {
+            let cells = self.cells(region);
+
+            for s in 0..n {
+                cover.add_constraint(cells.iter().map(|(r, c)| (*r, *c, s)).collect(), true);
+            }
+        }
+    }
+
+    fn design(&self, square: LatinSquare) -> GerechteDesign {
+        GerechteDesign {
+            square,
+            regions: self.clone(),
+        }
+    }
+
+    // Every gerechte design with these regions, sorted by the square.
+    pub fn designs(&self) -> Vec<GerechteDesign> {
+        self.cover()
+            .squares()
+            .into_iter()
+            .map(|s| self.design(s))
+            .collect()
+    }
+
+    pub fn count_designs(&self) -> usize {
+        self.cover().count()
+    }
+
+    // The partial latin square with the clues filled in, as an exact cover problem with the regions.
+    fn cover_with_clues(&self, clues: &PartialLatinSquare) -> Option<LatinSquareCover> {
+        if clues.order() != self.order() || !clues.is_valid() {
+            return None;
+        }
+
+        let mut cover = LatinSquareCover::from_partial(clues);
+        self.add_regions(&mut cover);
+
+        Some(cover)
+    }
+
+    // One design agreeing with the clues, or None if there is none.
+    pub fn complete(&self, clues: &PartialLatinSquare) -> Option<GerechteDesign> {
+        self.cover_with_clues(clues)?
+            .first()
+            .map(|s| self.design(s))
+    }
+
+    // Every design agreeing with the clues, sorted by the square.
+    pub fn completions(&self, clues: &PartialLatinSquare) -> Vec<GerechteDesign> {
+        match self.cover_with_clues(clues) {
+            Some(cover) => cover
+                .squares()
+                .into_iter()
+                .map(|s| self.design(s))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    // The number of designs agreeing with the clues. A well posed Sudoku has exactly one.
+    pub fn count_completions(&self, clues: &PartialLatinSquare) -> usize {
+        match self.cover_with_clues(clues) {
+            Some(cover) => cover.count(),
+            None => 0,
+        }
+    }
+}
+
+impl GerechteDesign {
+    // Checks that the square has every symbol once in every region.
+    pub fn new(square: LatinSquare, regions: Regions) -> Result<GerechteDesign, GerechteError> {
+        regions.check(&square)?;
+
+        Ok(GerechteDesign { square, regions })
+    }
+}
+
+// The square, with a "|" between cells in different regions of the same row.
+impl Display for GerechteDesign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self
+            .square
+            .0
+            .iter()
+            .zip(self.regions.0.iter())
+            .map(|(row, regions)| {
+                let mut text = row[0].to_string();
+
+                for c in 1..row.len() {
+                    text.push_str(if regions[c] == regions[c - 1] {
+                        " "
+                    } else {
+                        " | "
+                    });
+                    text.push_str(&row[c].to_string());
+                }
+
+                text
+            })
+            .collect();
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use super::LatinSquare;
+use super::Permutation;
+
+// An isomorphism class of latin squares under the conjugation LatinSquare::apply_permutation.
+#[derive(Debug, Clone)]
+pub struct IsomorphismClass {
+    // The lexicographically smallest square in the class.
+    pub representative: LatinSquare,
+    // The number of permutations p with representative.apply_permutation(p) == representative.
+    pub automorphisms: usize,
+    // The number of labelled squares in the class, n! / automorphisms.
+    pub orbit_size: usize,
+}
+
+impl IsomorphismClass {
+    // Every square in the class, together with a permutation taking the representative to it.
+    // The permutation is the first one in perms that does so.
+    pub fn orbit(&self, perms: &[Permutation]) -> Vec<(Permutation, LatinSquare)> {
+        let mut result: Vec<(Permutation, LatinSquare)> = vec![];
+        let mut seen: HashSet<LatinSquare> = HashSet::new();
+
+        for p in perms {
+            let mut square = self.representative.clone();
+            square.apply_permutation(p.clone());
+
+            if seen.insert(square.clone()) {
+                result.push((p.clone(), square));
+            }
+        }
+
+        result
+    }
+}
@@ -1,20 +1,39 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 
 use indicatif::ProgressBar;
 
-mod partial_latin_square;
-use partial_latin_square::PartialLatinSquare;
+mod backtracking;
 
+mod isomorphism_classes;
+
+mod all_latin_squares;
+pub use all_latin_squares::AllLatinSquares;
+
+mod isomorphism_search;
+
+use super::AffineMap;
 use super::AllAffineAutomorphisms;
+use super::ClassSummary;
+use super::ExactCover;
+use super::IsomorphismClass;
+use super::Isotopism;
 use super::LatinSquareClassification;
+use super::LatinSquareCover;
 use super::LatinStructure;
 use super::LatinType;
+use super::MultiplicationReport;
+use super::Parastrophy;
 use super::Permutation;
+use super::PermutationGroup;
 use super::Sidedness;
+use super::SummarySums;
+use super::{LoopProperties, QuasigroupProperties};
 
 // Represented as a vector of the rows of the latin square, where the rows are vectors of usize.
 // Always non-empty, square, and satisfies the latin square property.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct LatinSquare(pub Vec<Vec<usize>>);
 
 impl LatinSquare {
@@ -54,22 +73,562 @@ impl LatinSquare {
         println!("{}", super_string)
     }
 
-    // Generates all latin squares. No fixed identity.
+    // Generates all latin squares, sorted. No fixed identity. Searched with LatinSquareCover, one symbol in the
+    // first cell at a time.
     pub fn generate_all(n: usize) -> Vec<LatinSquare> {
         let mut result: Vec<LatinSquare> = vec![];
         let bar = ProgressBar::new(n as u64);
 
         for i in 0..n {
             bar.inc(1);
-            result.append(&mut partial_latin_square::latin_square_recursion(
-                n,
-                PartialLatinSquare(vec![vec![i]]),
-            ));
+
+            let mut cover = LatinSquareCover::new(n);
+            cover.fix(0, 0, i);
+
+            result.append(&mut cover.squares());
+        }
+
+        result
+    }
+
+    // Lazily iterates over all latin squares, in the same order as generate_all.
+    pub fn iter_all(n: usize) -> AllLatinSquares {
+        AllLatinSquares::new(n)
+    }
+
+    // Generates all reduced latin squares, i.e. the ones where the first row and column are 0, 1, ..., n - 1.
+    pub fn generate_reduced(n: usize) -> Vec<LatinSquare> {
+        let mut result: Vec<LatinSquare> = vec![];
+
+        backtracking::for_each_reduced_latin_square(n, &mut |rows| {
+            result.push(LatinSquare(rows.to_vec()))
+        });
+
+        result
+    }
+
+    // The number of reduced latin squares, without storing them.
+    pub fn count_reduced(n: usize) -> usize {
+        let mut count: usize = 0;
+
+        backtracking::for_each_reduced_latin_square(n, &mut |_| count += 1);
+
+        count
+    }
+
+    // The number of latin squares, or None if it does not fit in a usize, which happens from n = 8.
+    pub fn count_all(n: usize) -> Option<usize> {
+        LatinSquare::count_all_from_reduced(n, LatinSquare::count_reduced(n))
+    }
+
+    // L(n) = n! (n - 1)! R(n), where R(n) is the number of reduced latin squares, or None if it does not fit in a
+    // usize. The empty square is the only one of order 0, and it is reduced.
+    pub fn count_all_from_reduced(n: usize, reduced: usize) -> Option<usize> {
+        if n == 0 {
+            return Some(reduced);
+        }
+
+        crate::common::checked_factorial(n)?
+            .checked_mul(crate::common::checked_factorial(n - 1)?)?
+            .checked_mul(reduced)
+    }
+
+    // Every latin square that reduces to this one. Relabelling the symbols so the first row is in natural order,
+    // and then reordering the rows so the first column is in natural order, is a bijection between the latin squares
+    // and the pairs of a reduced latin square and (symbol permutation, permutation of the rows 1, ..., n - 1).
+    pub fn expand_reduced(&self) -> Vec<LatinSquare> {
+        let n = self.0.len();
+
+        let symbol_perms = Permutation::generate_all(n);
+        let row_perms = Permutation::generate_all(n - 1);
+
+        let mut result: Vec<LatinSquare> = vec![];
+
+        for symbols in symbol_perms.iter() {
+            let relabelled: Vec<Vec<usize>> = self
+                .0
+                .iter()
+                .map(|row| row.iter().map(|v| symbols.0[*v]).collect())
+                .collect();
+
+            for rows in row_perms.iter() {
+                let mut square: Vec<Vec<usize>> = vec![relabelled[0].clone()];
+
+                for r in rows.0.iter() {
+                    square.push(relabelled[r + 1].clone());
+                }
+
+                result.push(LatinSquare(square));
+            }
         }
 
         result
     }
 
+    // Generates all latin squares by expanding the reduced ones. Sorted the same way as generate_all.
+    pub fn generate_all_from_reduced(n: usize) -> Vec<LatinSquare> {
+        let mut result: Vec<LatinSquare> = vec![];
+
+        for reduced in LatinSquare::generate_reduced(n) {
+            result.append(&mut reduced.expand_reduced());
+        }
+
+        result.sort();
+
+        result
+    }
+
+    // Generates one representative of every isomorphism class, i.e. of every orbit under apply_permutation,
+    // together with the size of the orbit. For n = 5 these are 1411 classes instead of 161280 squares.
+    pub fn isomorphism_classes(n: usize) -> Vec<IsomorphismClass> {
+        let n_factorial = crate::common::factorial(n);
+
+        isomorphism_classes::isomorphism_class_representatives(n)
+            .into_iter()
+            .map(|(representative, automorphisms)| IsomorphismClass {
+                representative,
+                automorphisms,
+                orbit_size: n_factorial / automorphisms,
+            })
+            .collect()
+    }
+
+    // The permutations p with p(xy) = p(x)p(y), i.e. the permutations for which apply_permutation leaves
+    // the square unchanged. An automorphism is determined by the images of a generating set of the quasigroup,
+    // so only those are searched instead of all n! permutations.
+    pub fn automorphism_group(&self) -> PermutationGroup {
+        let mut automorphisms = isomorphism_search::isomorphisms(self, self, false);
+        automorphisms.sort_by_cached_key(|p| p.rank());
+        let order = automorphisms.len();
+
+        // Picks generators greedily: every automorphism not generated by the earlier ones becomes a generator.
+        let mut generators: Vec<Permutation> = vec![];
+        let mut group = PermutationGroup::from_generators(self.0.len(), vec![]);
+
+        for p in automorphisms {
+            if group.order() == order {
+                break;
+            }
+
+            if !group.contains(&p) {
+                generators.push(p);
+                group = PermutationGroup::from_generators(self.0.len(), generators.clone());
+            }
+        }
+
+        group
+    }
+
+    // The square with rows, columns and symbols permuted, i.e. result[a(i)][b(j)] = c(self[i][j]).
+    pub fn apply_isotopism(&self, isotopism: &Isotopism) -> LatinSquare {
+        let (a, b, c) = isotopism;
+        let mut result = self.clone();
+
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                result.0[a.0[i]][b.0[j]] = c.0[*v];
+            }
+        }
+
+        result
+    }
+
+    // The isotopisms mapping the square to itself.
+    pub fn autotopisms(&self) -> Vec<Isotopism> {
+        isomorphism_search::isotopisms(self, self, false)
+    }
+
+    // The number of autoparatopisms, i.e. a conjugate followed by an isotopism mapping the square to itself.
+    // Every conjugate isotopic to the square contributes as many as there are autotopisms.
+    pub fn count_autoparatopisms(&self) -> usize {
+        self.count_isotopic_conjugates() * self.autotopisms().len()
+    }
+
+    // The number of the six conjugates that are isotopic to the square, counted with multiplicity.
+    fn count_isotopic_conjugates(&self) -> usize {
+        super::PARASTROPHIES
+            .into_iter()
+            .map(|p| self.conjugate(p))
+            .filter(|c| c == self || !isomorphism_search::isotopisms(c, self, true).is_empty())
+            .count()
+    }
+
+    // The conjugate, given by permuting the roles of row, column and symbol in the triples (i, j, self[i][j]).
+    pub fn conjugate(&self, parastrophy: Parastrophy) -> LatinSquare {
+        let order = parastrophy.order();
+        let mut result = self.clone();
+
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, v) in row.iter().enumerate() {
+                let triple = [i, j, *v];
+                result.0[triple[order[0]]][triple[order[1]]] = triple[order[2]];
+            }
+        }
+
+        result
+    }
+
+    // The parastrophies whose conjugate is the square itself. Identity is always one of them, Transpose is
+    // one exactly when the square is commutative, and all six are when it is totally symmetric.
+    pub fn conjugate_symmetry(&self) -> Vec<Parastrophy> {
+        super::PARASTROPHIES
+            .into_iter()
+            .filter(|p| self.conjugate(*p) == *self)
+            .collect()
+    }
+
+    // A canonical form under apply_permutation, together with the permutation taking the square to it.
+    // Two squares are isomorphic exactly when they have the same canonical form. It is not necessarily the
+    // representative of LatinSquare::isomorphism_classes, which is the smallest square in the class.
+    pub fn canonical_form(&self) -> (LatinSquare, Permutation) {
+        let p = isomorphism_search::canonical_labelling(self);
+
+        let mut canonical_form = self.clone();
+        canonical_form.apply_permutation(p.clone());
+
+        (canonical_form, p)
+    }
+
+    // A permutation p with self.apply_permutation(p) == *other, if there is one. Elements are only mapped to
+    // elements with the same invariants, such as the cycle types of the rows and columns.
+    pub fn is_isomorphic(&self, other: &LatinSquare) -> Option<Permutation> {
+        isomorphism_search::isomorphisms(self, other, true).pop()
+    }
+
+    // The lexicographically smallest square isotopic to the square. Two squares are isotopic exactly when
+    // they have the same canonical form.
+    pub fn isotopy_canonical_form(&self) -> LatinSquare {
+        isomorphism_search::canonical_isotope(self)
+    }
+
+    // The lexicographically smallest square paratopic to the square, i.e. isotopic to one of its conjugates.
+    pub fn main_class_canonical_form(&self) -> LatinSquare {
+        super::PARASTROPHIES
+            .into_iter()
+            .map(|p| isomorphism_search::canonical_isotope(&self.conjugate(p)))
+            .min()
+            .unwrap()
+    }
+
+    // Whether p(x + y) = p(x) + p(y) for all x and y.
+    pub fn is_automorphism(&self, p: &Permutation) -> bool {
+        let n = self.0.len();
+
+        p.0.len() == n
+            && (0..n).all(|x| (0..n).all(|y| p.0[self.0[x][y]] == self.0[p.0[x]][p.0[y]]))
+    }
+
+    // Every different map x -> (a + p(x)) + b, where p is an automorphism and either translation can be left out.
+    // Maps that are equal as permutations are only listed once, in the first of these forms found.
+    // Sorted by the rank of the permutation.
+    pub fn affine_maps(&self) -> Vec<AffineMap> {
+        let n = self.0.len();
+
+        let translations: Vec<Option<usize>> =
+            std::iter::once(None).chain((0..n).map(Some)).collect();
+
+        let mut maps: BTreeMap<usize, AffineMap> = BTreeMap::new();
+
+        for automorphism in self.automorphism_group().elements() {
+            for left in translations.iter() {
+                for right in translations.iter() {
+                    let map = AffineMap {
+                        left: *left,
+                        automorphism: automorphism.clone(),
+                        right: *right,
+                    };
+
+                    maps.entry(map.permutation(self).rank()).or_insert(map);
+                }
+            }
+        }
+
+        maps.into_values().collect()
+    }
+
+    // The group generated by the automorphisms and the translations x -> a + x and x -> x + a. For a group
+    // every element is an affine map, so its order is the number of affine_maps.
+    pub fn affine_group(&self) -> PermutationGroup {
+        let n = self.0.len();
+
+        let mut generators: Vec<Permutation> = self.automorphism_group().strong_generators();
+
+        for a in 0..n {
+            generators.push(self.addition_permutation(a, &Sidedness::Left));
+            generators.push(self.addition_permutation(a, &Sidedness::Right));
+        }
+
+        PermutationGroup::from_generators(n, generators)
+    }
+
+    // The group generated by the left translations x -> a + x.
+    pub fn left_multiplication_group(&self) -> PermutationGroup {
+        self.translation_group(&[Sidedness::Left])
+    }
+
+    // The group generated by the right translations x -> x + a.
+    pub fn right_multiplication_group(&self) -> PermutationGroup {
+        self.translation_group(&[Sidedness::Right])
+    }
+
+    // The group generated by both the left and the right translations.
+    pub fn multiplication_group(&self) -> PermutationGroup {
+        self.translation_group(&super::SIDES)
+    }
+
+    fn translation_group(&self, sides: &[Sidedness]) -> PermutationGroup {
+        let n = self.0.len();
+
+        let mut generators: Vec<Permutation> = vec![];
+
+        for a in 0..n {
+            for side in sides {
+                generators.push(self.addition_permutation(a, side));
+            }
+        }
+
+        PermutationGroup::from_generators(n, generators)
+    }
+
+    // The elements of the multiplication group fixing the identity. Only defined for loops.
+    pub fn inner_mapping_group(&self) -> Option<PermutationGroup> {
+        let e = self.identity()?;
+
+        Some(self.multiplication_group().stabilizer(e))
+    }
+
+    // The elements a with a(xy) = (ax)y for all x and y.
+    pub fn left_nucleus(&self) -> Vec<usize> {
+        let s = &self.0;
+
+        self.elements_where(|a, x, y| s[a][s[x][y]] == s[s[a][x]][y])
+    }
+
+    // The elements a with x(ay) = (xa)y for all x and y.
+    pub fn middle_nucleus(&self) -> Vec<usize> {
+        let s = &self.0;
+
+        self.elements_where(|a, x, y| s[x][s[a][y]] == s[s[x][a]][y])
+    }
+
+    // The elements a with x(ya) = (xy)a for all x and y.
+    pub fn right_nucleus(&self) -> Vec<usize> {
+        let s = &self.0;
+
+        self.elements_where(|a, x, y| s[x][s[y][a]] == s[s[x][y]][a])
+    }
+
+    // The elements in all three nuclei.
+    pub fn nucleus(&self) -> Vec<usize> {
+        let middle = self.middle_nucleus();
+        let right = self.right_nucleus();
+
+        self.left_nucleus()
+            .into_iter()
+            .filter(|a| middle.contains(a) && right.contains(a))
+            .collect()
+    }
+
+    // The elements a with ax = xa for all x.
+    pub fn commutant(&self) -> Vec<usize> {
+        let n = self.0.len();
+
+        (0..n)
+            .filter(|a| (0..n).all(|x| self.0[*a][x] == self.0[x][*a]))
+            .collect()
+    }
+
+    // The elements of the nucleus that are also in the commutant.
+    pub fn center(&self) -> Vec<usize> {
+        let commutant = self.commutant();
+
+        self.nucleus()
+            .into_iter()
+            .filter(|a| commutant.contains(a))
+            .collect()
+    }
+
+    // The elements a for which the condition holds for all x and y.
+    fn elements_where(&self, condition: impl Fn(usize, usize, usize) -> bool) -> Vec<usize> {
+        let n = self.0.len();
+
+        (0..n)
+            .filter(|a| (0..n).all(|x| (0..n).all(|y| condition(*a, x, y))))
+            .collect()
+    }
+
+    // The element z with az = y, written a \ y.
+    pub fn left_division(&self, a: usize, y: usize) -> usize {
+        self.0[a].iter().position(|z| *z == y).unwrap()
+    }
+
+    // The element z with zb = x, written x / b.
+    pub fn right_division(&self, x: usize, b: usize) -> usize {
+        (0..self.0.len()).find(|z| self.0[*z][b] == x).unwrap()
+    }
+
+    // The loop x o y = (x / b)(a \ y), which is isotopic to the square and has the identity ab. Every loop
+    // isotopic to the square is isomorphic to one of these.
+    pub fn principal_loop_isotope(&self, a: usize, b: usize) -> LatinSquare {
+        let n = self.0.len();
+
+        let left: Vec<usize> = (0..n).map(|x| self.right_division(x, b)).collect();
+        let right: Vec<usize> = (0..n).map(|y| self.left_division(a, y)).collect();
+
+        LatinSquare(
+            left.iter()
+                .map(|x| right.iter().map(|y| self.0[*x][*y]).collect())
+                .collect(),
+        )
+    }
+
+    // The canonical forms, as in LatinSquare::canonical_form, of every principal loop isotope.
+    pub fn principal_loop_isotopes(&self) -> BTreeSet<LatinSquare> {
+        let n = self.0.len();
+
+        let mut loops: BTreeSet<LatinSquare> = BTreeSet::new();
+
+        for a in 0..n {
+            for b in 0..n {
+                loops.insert(self.principal_loop_isotope(a, b).canonical_form().0);
+            }
+        }
+
+        loops
+    }
+
+    pub fn multiplication_report(&self) -> MultiplicationReport {
+        MultiplicationReport {
+            left_multiplication_group: self.left_multiplication_group().order(),
+            right_multiplication_group: self.right_multiplication_group().order(),
+            multiplication_group: self.multiplication_group().order(),
+            inner_mapping_group: self.inner_mapping_group().map(|g| g.order()),
+            left_nucleus: self.left_nucleus(),
+            middle_nucleus: self.middle_nucleus(),
+            right_nucleus: self.right_nucleus(),
+            nucleus: self.nucleus(),
+            commutant: self.commutant(),
+            center: self.center(),
+        }
+    }
+
+    // Every transversal, i.e. n cells with one in every row and every column, and every symbol once.
+    // Given as the column of the cell in every row, sorted.
+    pub fn transversals(&self) -> Vec<Vec<usize>> {
+        let n = self.0.len();
+
+        let mut transversals: Vec<Vec<usize>> = self
+            .transversal_cover()
+            .solutions()
+            .iter()
+            .map(|cells| cells.iter().map(|cell| cell % n).collect())
+            .collect();
+
+        transversals.sort();
+
+        transversals
+    }
+
+    // The number of transversals, without storing them.
+    pub fn count_transversals(&self) -> usize {
+        self.transversal_cover().count_solutions()
+    }
+
+    // The rows, the columns and the symbols as an exact cover problem, with one option for every cell.
+    fn transversal_cover(&self) -> ExactCover {
+        let n = self.0.len();
+
+        let mut cover = ExactCover::new(3 * n, 0);
+
+        for r in 0..n {
+            for c in 0..n {
+                cover.add_row(&[r, n + c, 2 * n + self.0[r][c]]);
+            }
+        }
+
+        cover
+    }
+
+    // Whether every pair of symbols (L[r][c], M[r][c]) appears exactly once.
+    pub fn is_orthogonal(&self, other: &LatinSquare) -> bool {
+        let n = self.0.len();
+
+        if other.0.len() != n {
+            return false;
+        }
+
+        let mut seen: Vec<bool> = vec![false; n * n];
+
+        for r in 0..n {
+            for c in 0..n {
+                let pair = self.0[r][c] * n + other.0[r][c];
+
+                if seen[pair] {
+                    return false;
+                }
+
+                seen[pair] = true;
+            }
+        }
+
+        true
+    }
+
+    // Every latin square orthogonal to this one with the first row 0, 1, ..., n - 1, sorted. The cells of a symbol
+    // in an orthogonal mate form a transversal, so the mates are found as the ways to split the cells into n
+    // disjoint transversals. Every other mate is one of these with the symbols relabelled.
+    pub fn orthogonal_mates(&self) -> Vec<LatinSquare> {
+        let n = self.0.len();
+        let transversals = self.transversals();
+
+        let mut cover = ExactCover::new(n * n, 0);
+
+        for t in transversals.iter() {
+            let cells: Vec<usize> = t.iter().enumerate().map(|(r, c)| r * n + c).collect();
+            cover.add_row(&cells);
+        }
+
+        let mut mates: Vec<LatinSquare> = cover
+            .solutions()
+            .iter()
+            .map(|rows| {
+                let mut square: Vec<Vec<usize>> = vec![vec![0; n]; n];
+
+                for row in rows {
+                    // The symbol of a transversal is the column it has in the first row.
+                    let t = &transversals[*row];
+
+                    for (r, c) in t.iter().enumerate() {
+                        square[r][*c] = t[0];
+                    }
+                }
+
+                LatinSquare(square)
+            })
+            .collect();
+
+        mates.sort();
+
+        mates
+    }
+
+    // The direct product of an n by n and an m by m latin square, of order nm. The element x of this square and y
+    // of the other are the element xm + y of the product, which is computed in both squares independently.
+    pub fn direct_product(&self, other: &LatinSquare) -> LatinSquare {
+        let m = other.0.len();
+        let nm = self.0.len() * m;
+
+        LatinSquare(
+            (0..nm)
+                .map(|x| {
+                    (0..nm)
+                        .map(|y| self.0[x / m][y / m] * m + other.0[x % m][y % m])
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
     // Applies a permutation to a latin square. I.e. gives the conjugacy of the latin square.
     pub fn apply_permutation(&mut self, mut p: Permutation) {
         // Apply the permutation to every element in the latin square.
@@ -98,33 +657,71 @@ impl LatinSquare {
         }
     }
 
-    fn left_identity(&self) -> bool {
-        // Check if it contains a right-identity
+    // The element e with ex = x for all x. In the row-major convention square[x][y] = xy,
+    // this is the row that reads 0, 1, ..., n - 1.
+    pub fn left_identity(&self) -> Option<usize> {
         let standard: Vec<usize> = (0..self.0.len()).collect();
 
-        self.0.contains(&standard)
+        self.0.iter().position(|row| row == &standard)
     }
 
-    fn right_identity(&self) -> bool {
-        let mut standard: Vec<usize> = (0..self.0.len()).collect();
+    // The element e with xe = x for all x, i.e. the column that reads 0, 1, ..., n - 1.
+    pub fn right_identity(&self) -> Option<usize> {
+        let n = self.0.len();
 
-        let column = self.0[0].iter().position(|&x| x == 0).unwrap();
+        (0..n).find(|&e| (0..n).all(|x| self.0[x][e] == x))
+    }
 
-        for row in self.0.iter().rev() {
-            let c = standard.pop().unwrap();
+    // The element e with ex = xe = x for all x. If there is both a left identity e and a right identity f,
+    // then e = ef = f.
+    pub fn identity(&self) -> Option<usize> {
+        match (self.left_identity(), self.right_identity()) {
+            (Some(e), Some(_)) => Some(e),
+            _ => None,
+        }
+    }
 
-            if c != row[column] {
-                return false;
+    pub fn commutative(&self) -> bool {
+        for a in 1..self.0.len() {
+            for b in 0..a {
+                if self.0[a][b] != self.0[b][a] {
+                    return false;
+                }
             }
         }
 
         true
     }
 
-    fn commutative(&self) -> bool {
-        for a in 1..self.0.len() {
-            for b in 0..a {
-                if self.0[a][b] != self.0[b][a] {
+    // x(yz) = (xy)z for all x, y, z.
+    pub fn associative(&self) -> bool {
+        let n = self.0.len();
+        let s = &self.0;
+
+        for x in 0..n {
+            for y in 0..n {
+                for z in 0..n {
+                    if s[x][s[y][z]] != s[s[x][y]][z] {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn idempotent(&self) -> bool {
+        (0..self.0.len()).all(|x| self.0[x][x] == x)
+    }
+
+    pub fn flexible(&self) -> bool {
+        let n = self.0.len();
+        let s = &self.0;
+
+        for x in 0..n {
+            for y in 0..n {
+                if s[x][s[y][x]] != s[s[x][y]][x] {
                     return false;
                 }
             }
@@ -133,54 +730,147 @@ impl LatinSquare {
         true
     }
 
-    fn associative(&self) -> bool {
-        todo!()
+    pub fn medial(&self) -> bool {
+        let n = self.0.len();
+        let s = &self.0;
+
+        for x in 0..n {
+            for y in 0..n {
+                for u in 0..n {
+                    for v in 0..n {
+                        if s[s[x][y]][s[u][v]] != s[s[x][u]][s[y][v]] {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        true
     }
 
-    // Classifies the Latin square as a quasigroup, loop, group or abelian group.
-    pub fn classify(&self) -> LatinStructure {
-        if !self.left_identity() {
-            return LatinStructure::Quasigroup;
+    pub fn left_distributive(&self) -> bool {
+        let n = self.0.len();
+        let s = &self.0;
+
+        for x in 0..n {
+            for y in 0..n {
+                for z in 0..n {
+                    if s[x][s[y][z]] != s[s[x][y]][s[x][z]] {
+                        return false;
+                    }
+                }
+            }
         }
 
-        // Check if it contains a left-identity
-        if !self.right_identity() {
-            return LatinStructure::Quasigroup;
+        true
+    }
+
+    pub fn right_distributive(&self) -> bool {
+        let n = self.0.len();
+        let s = &self.0;
+
+        for x in 0..n {
+            for y in 0..n {
+                for z in 0..n {
+                    if s[s[y][z]][x] != s[s[y][x]][s[z][x]] {
+                        return false;
+                    }
+                }
+            }
         }
 
-        // Check if associative
-        let a: Vec<usize>;
+        true
+    }
 
-        let column = self.0[0].iter().position(|&x| x == 0).unwrap();
+    pub fn totally_symmetric(&self) -> bool {
+        let n = self.0.len();
+        let s = &self.0;
 
-        if column == 0 {
-            a = (1..self.0.len()).collect()
-        } else if column == self.0.len() - 1 {
-            a = (0..(self.0.len() - 1)).collect()
-        } else {
-            let mut a_1: Vec<usize> = (0..column).collect();
-            a_1.append(&mut ((column + 1)..self.0.len()).collect());
+        for x in 0..n {
+            for y in 0..n {
+                if s[x][y] != s[y][x] || s[x][s[x][y]] != y {
+                    return false;
+                }
+            }
+        }
 
-            a = a_1;
+        true
+    }
+
+    pub fn unipotent(&self) -> bool {
+        (0..self.0.len()).all(|x| self.0[x][x] == self.0[0][0])
+    }
+
+    fn loop_properties(&self, e: usize) -> LoopProperties {
+        let n = self.0.len();
+        let s = &self.0;
+
+        // Left and right inverses, x^λ x = e and x x^ρ = e.
+        let left_inverse: Vec<usize> = (0..n)
+            .map(|x| (0..n).find(|&y| s[y][x] == e).unwrap())
+            .collect();
+        let right_inverse: Vec<usize> = (0..n)
+            .map(|x| (0..n).find(|&y| s[x][y] == e).unwrap())
+            .collect();
+
+        let mut properties = LoopProperties {
+            moufang: true,
+            left_bol: true,
+            right_bol: true,
+            left_inverse_property: true,
+            right_inverse_property: true,
         };
 
-        let mut associativity_triplets: Vec<(usize, usize, usize)> = vec![];
+        for x in 0..n {
+            for y in 0..n {
+                if s[left_inverse[x]][s[x][y]] != y {
+                    properties.left_inverse_property = false;
+                }
+                if s[s[y][x]][right_inverse[x]] != y {
+                    properties.right_inverse_property = false;
+                }
 
-        for x in &a {
-            for y in &a {
-                for z in &a {
-                    associativity_triplets.push((*x, *y, *z));
+                for z in 0..n {
+                    if s[z][s[x][s[z][y]]] != s[s[s[z][x]][z]][y] {
+                        properties.moufang = false;
+                    }
+                    if s[x][s[y][s[x][z]]] != s[s[x][s[y][x]]][z] {
+                        properties.left_bol = false;
+                    }
+                    if s[s[s[z][x]][y]][x] != s[z][s[s[x][y]][x]] {
+                        properties.right_bol = false;
+                    }
                 }
             }
         }
 
-        for (a, b, c) in associativity_triplets {
-            let left = self.0[a][self.0[b][c]];
-            let right = self.0[self.0[a][b]][c];
+        properties
+    }
 
-            if left != right {
-                return LatinStructure::Loop;
-            }
+    // Checks every algebraic property in QuasigroupProperties.
+    pub fn properties(&self) -> QuasigroupProperties {
+        QuasigroupProperties {
+            associative: self.associative(),
+            idempotent: self.idempotent(),
+            flexible: self.flexible(),
+            medial: self.medial(),
+            left_distributive: self.left_distributive(),
+            right_distributive: self.right_distributive(),
+            totally_symmetric: self.totally_symmetric(),
+            unipotent: self.unipotent(),
+            loop_properties: self.identity().map(|e| self.loop_properties(e)),
+        }
+    }
+
+    // Classifies the Latin square as a quasigroup, loop, group or abelian group.
+    pub fn classify(&self) -> LatinStructure {
+        if self.identity().is_none() {
+            return LatinStructure::Quasigroup;
+        }
+
+        if !self.associative() {
+            return LatinStructure::Loop;
         }
 
         // Check if symmetric
@@ -192,37 +882,13 @@ impl LatinSquare {
     }
 
     pub fn classify_structure(&self) -> LatinType {
-        let class = self.classify();
-
-        // Left-id, right-id, commutative
-        let flags: (bool, bool, bool) = match class {
-            LatinStructure::Abelian => (true, true, true),
-            LatinStructure::Group => (true, true, false),
-            LatinStructure::Loop => {
-                if self.commutative() {
-                    (true, true, true)
-                } else {
-                    (true, true, false)
-                }
-            }
-            LatinStructure::Quasigroup => {
-                if self.commutative() {
-                    (false, false, true)
-                } else if self.left_identity() {
-                    (true, false, false)
-                } else if self.right_identity() {
-                    (false, true, false)
-                } else {
-                    (false, false, false)
-                }
-            }
-        };
-
         LatinType {
-            commutative: flags.2,
-            left_identity: flags.0,
-            right_identity: flags.1,
-            structure: class,
+            structure: self.classify(),
+            left_identity: self.left_identity(),
+            right_identity: self.right_identity(),
+            identity: self.identity(),
+            commutative: self.commutative(),
+            properties: self.properties(),
         }
     }
 
@@ -266,45 +932,366 @@ impl Display for LatinSquare {
     }
 }
 
+// Finds every automorphism and affine automorphism of a single latin square.
+// The permutations must be Permutation::generate_all(n), since they are looked up by Permutation::rank.
+pub fn classify_latin_square(
+    s: &LatinSquare,
+    index: usize,
+    perms: &[Permutation],
+) -> LatinSquareClassification {
+    let mut all_affine_automorphisms: Vec<AllAffineAutomorphisms> =
+        vec![(false, vec![]); perms.len()];
+
+    for p in s.automorphism_group().elements() {
+        let i = p.rank();
+        all_affine_automorphisms[i].0 = true;
+
+        for v in 0..s.0.len() {
+            for side in super::SIDES {
+                let affine_automorphism = s.addition_permutation(v, &side).compose(&p);
+                let found_permutation = affine_automorphism.rank();
+                all_affine_automorphisms[found_permutation]
+                    .1
+                    .push((i, v, side));
+            }
+        }
+    }
+
+    let autotopisms = s.autotopisms().len();
+
+    LatinSquareClassification {
+        class: s.classify_structure(),
+        index,
+        square: s.clone(),
+        all_permutations_all_affine_automorphisms: all_affine_automorphisms,
+        autotopisms,
+        autoparatopisms: autotopisms * s.count_isotopic_conjugates(),
+        transversals: s.count_transversals(),
+        conjugate_symmetry: s.conjugate_symmetry(),
+    }
+}
+
+// Lazily classifies the squares one at a time, e.g. classify_latin_squares(LatinSquare::iter_all(n), &perms).
+pub fn classify_latin_squares<'a, I>(
+    squares: I,
+    perms: &'a [Permutation],
+) -> impl Iterator<Item = LatinSquareClassification> + 'a
+where
+    I: IntoIterator,
+    I::Item: Borrow<LatinSquare>,
+    I::IntoIter: 'a,
+{
+    squares
+        .into_iter()
+        .enumerate()
+        .map(move |(j, s)| classify_latin_square(s.borrow(), j, perms))
+}
+
 pub fn classify_all_latin_squares(
     squares: &[LatinSquare],
     perms: &[Permutation],
+) -> Vec<LatinSquareClassification> {
+    classify_latin_squares(squares, perms).collect()
+}
+
+// Same as classify_all_latin_squares, but split across threads. The result is in the same order.
+pub fn classify_all_latin_squares_parallel(
+    squares: &[LatinSquare],
+    perms: &[Permutation],
+    threads: usize,
+) -> Vec<LatinSquareClassification> {
+    let bar = ProgressBar::new(squares.len() as u64);
+
+    crate::common::parallel_map(squares, threads, &bar, |j, s| {
+        classify_latin_square(s, j, perms)
+    })
+}
+
+// Classifies every square in an isomorphism class while only searching for automorphisms of the representative.
+// If s = r.apply_permutation(σ), then σpσ^-1 is an automorphism of s exactly when p is one of r, and the
+// affine automorphism v + p of r becomes σ(v) + σpσ^-1. The squares are in the order of IsomorphismClass::orbit,
+// and every index is 0. Isomorphic squares are isotopic, so they share the autotopism, autoparatopism and
+// transversal counts, and since conjugation commutes with apply_permutation they have the same conjugate symmetry.
+// These are only searched for on the representative and copied to the rest of the orbit.
+pub fn classify_isomorphism_class(
+    class: &IsomorphismClass,
+    perms: &[Permutation],
 ) -> Vec<LatinSquareClassification> {
     let mut result: Vec<LatinSquareClassification> = vec![];
 
-    for (j, s) in squares.iter().enumerate() {
+    let classification = classify_latin_square(&class.representative, 0, perms);
+
+    // The invariants of the class, without the automorphisms of the representative.
+    let invariants = LatinSquareClassification {
+        all_permutations_all_affine_automorphisms: vec![],
+        ..classification.clone()
+    };
+
+    for (sigma, square) in class.orbit(perms) {
+        let sigma_inverse = sigma.inverse();
+        let conjugate = |i: usize| sigma.compose(&perms[i]).compose(&sigma_inverse).rank();
+
         let mut all_affine_automorphisms: Vec<AllAffineAutomorphisms> =
             vec![(false, vec![]); perms.len()];
 
-        for (i, p) in perms.iter().enumerate() {
-            let mut w = s.clone();
-            w.apply_permutation(p.clone());
-
-            if w == *s {
-                all_affine_automorphisms[i].0 = true;
-
-                for v in 0..squares[0].0.len() {
-                    for side in super::SIDES {
-                        let affine_automorphism = s.addition_permutation(v, &side).compose(p);
-                        let found_permutation = perms
-                            .iter()
-                            .position(|x| x == &affine_automorphism)
-                            .unwrap();
-                        all_affine_automorphisms[found_permutation]
-                            .1
-                            .push((i, v, side));
-                    }
-                }
+        for (k, (automorphism, affine_automorphisms)) in classification
+            .all_permutations_all_affine_automorphisms
+            .iter()
+            .enumerate()
+        {
+            let c = &mut all_affine_automorphisms[conjugate(k)];
+
+            c.0 = *automorphism;
+
+            for (i, v, side) in affine_automorphisms {
+                c.1.push((conjugate(*i), sigma.0[*v], side.clone()));
             }
         }
 
+        // Same order as the affine automorphisms are found in classify_latin_square.
+        for c in all_affine_automorphisms.iter_mut() {
+            c.1.sort_by_key(|(i, v, side)| (*i, *v, side == &Sidedness::Right));
+        }
+
+        let mut class_type = classification.class.clone();
+        class_type.apply_permutation(&sigma);
+
         result.push(LatinSquareClassification {
-            class: s.classify_structure(),
-            index: j,
-            square: s.clone(),
+            class: class_type,
+            index: 0,
+            square,
             all_permutations_all_affine_automorphisms: all_affine_automorphisms,
+            ..invariants.clone()
         });
     }
 
     result
 }
+
+// The sums of the summary table for every latin square of order n. Only the representative of every isomorphism
+// class is classified, and SummarySums::add_class counts the rest of its orbit.
+pub fn summarize_fingerprints(classes: &[IsomorphismClass], perms: &[Permutation]) -> SummarySums {
+    let mut sums = SummarySums::new(perms);
+
+    for class in classes {
+        let classification = classify_latin_square(&class.representative, 0, perms);
+        sums.add_class(class, &classification, perms);
+    }
+
+    sums
+}
+
+// Same as summarize_fingerprints, but split across threads. Every thread collects its own sums, which are merged
+// at the end.
+pub fn summarize_fingerprints_parallel(
+    classes: &[IsomorphismClass],
+    perms: &[Permutation],
+    threads: usize,
+) -> SummarySums {
+    let bar = ProgressBar::new(classes.len() as u64);
+
+    crate::common::parallel_fold(
+        classes,
+        threads,
+        &bar,
+        || SummarySums::new(perms),
+        |sums, _, class| {
+            let classification = classify_latin_square(&class.representative, 0, perms);
+            sums.add_class(class, &classification, perms);
+        },
+    )
+    .into_iter()
+    .reduce(|mut a, b| {
+        a.merge(b);
+        a
+    })
+    .unwrap_or_else(|| SummarySums::new(perms))
+}
+
+// Classifies every latin square of order n with classify_isomorphism_class. The result is sorted and indexed
+// like LatinSquare::generate_all, so it is identical to calling classify_all_latin_squares on those squares.
+pub fn classify_all_isomorphism_classes(
+    classes: &[IsomorphismClass],
+    perms: &[Permutation],
+) -> Vec<LatinSquareClassification> {
+    let mut result: Vec<LatinSquareClassification> = vec![];
+
+    for class in classes {
+        result.append(&mut classify_isomorphism_class(class, perms));
+    }
+
+    sort_and_index(&mut result);
+
+    result
+}
+
+// Same as classify_all_isomorphism_classes, but split across threads, one class at a time.
+pub fn classify_all_isomorphism_classes_parallel(
+    classes: &[IsomorphismClass],
+    perms: &[Permutation],
+    threads: usize,
+) -> Vec<LatinSquareClassification> {
+    let bar = ProgressBar::new(classes.len() as u64);
+
+    let mut result: Vec<LatinSquareClassification> =
+        crate::common::parallel_map(classes, threads, &bar, |_, c| {
+            classify_isomorphism_class(c, perms)
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+
+    sort_and_index(&mut result);
+
+    result
+}
+
+// Splits the squares into isotopy classes. The classes are sorted by their canonical forms, which are
+// returned with them, and the squares keep their order within every class.
+pub fn partition_into_isotopy_classes(
+    squares: Vec<LatinSquare>,
+) -> Vec<(LatinSquare, Vec<LatinSquare>)> {
+    partition_by(squares, |s| s.isotopy_canonical_form())
+}
+
+// Same as partition_into_isotopy_classes, but for main classes.
+pub fn partition_into_main_classes(
+    squares: Vec<LatinSquare>,
+) -> Vec<(LatinSquare, Vec<LatinSquare>)> {
+    partition_by(squares, |s| s.main_class_canonical_form())
+}
+
+fn partition_by(
+    squares: Vec<LatinSquare>,
+    canonical_form: impl Fn(&LatinSquare) -> LatinSquare,
+) -> Vec<(LatinSquare, Vec<LatinSquare>)> {
+    let mut classes: BTreeMap<LatinSquare, Vec<LatinSquare>> = BTreeMap::new();
+
+    for square in squares {
+        classes
+            .entry(canonical_form(&square))
+            .or_default()
+            .push(square);
+    }
+
+    classes.into_iter().collect()
+}
+
+// For every isomorphism class, the indices of the classes of loops that its principal loop isotopes lie in.
+// These are the same for every square in the class, since isomorphic squares have isomorphic principal isotopes.
+// Every loop isotopic to a square is isomorphic to one of its principal isotopes, so the loops are the same for
+// the whole isotopy class, and they are only searched for once per isotopy class.
+pub fn principal_loop_isotope_classes(classes: &[IsomorphismClass]) -> Vec<BTreeSet<usize>> {
+    let loop_classes: BTreeMap<LatinSquare, usize> = classes
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.representative.identity().is_some())
+        .map(|(i, c)| (c.representative.canonical_form().0, i))
+        .collect();
+
+    // The loops of every isotopy class found so far, by the canonical form of each of the loops.
+    let mut found: BTreeMap<LatinSquare, BTreeSet<usize>> = BTreeMap::new();
+
+    let mut result: Vec<BTreeSet<usize>> = vec![];
+
+    for class in classes {
+        let first = class
+            .representative
+            .principal_loop_isotope(0, 0)
+            .canonical_form()
+            .0;
+
+        if !found.contains_key(&first) {
+            let loops = class.representative.principal_loop_isotopes();
+            let indices: BTreeSet<usize> = loops.iter().map(|l| loop_classes[l]).collect();
+
+            for l in loops {
+                found.insert(l, indices.clone());
+            }
+        }
+
+        result.push(found[&first].clone());
+    }
+
+    result
+}
+
+// Summarizes the isotopy classes of order n from its isomorphism classes. Every labelled square in an isomorphism
+// class has the same canonical form, so only the representatives are needed. Sorted by canonical form.
+pub fn summarize_isotopy_classes(
+    classes: &[IsomorphismClass],
+    threads: usize,
+) -> Vec<ClassSummary> {
+    let bar = ProgressBar::new(classes.len() as u64);
+
+    let canonical_forms = crate::common::parallel_map(classes, threads, &bar, |_, c| {
+        c.representative.isotopy_canonical_form()
+    });
+
+    let mut summaries: BTreeMap<LatinSquare, ClassSummary> = BTreeMap::new();
+
+    for (class, canonical_form) in classes.iter().zip(canonical_forms) {
+        let summary = summaries
+            .entry(canonical_form.clone())
+            .or_insert_with(|| ClassSummary {
+                autotopisms: canonical_form.autotopisms().len(),
+                autoparatopisms: canonical_form.count_autoparatopisms(),
+                transversals: canonical_form.count_transversals(),
+                canonical_form,
+                squares: 0,
+                isomorphism_classes: 0,
+                isotopy_classes: 1,
+                loops: 0,
+                automorphisms: BTreeSet::new(),
+            });
+
+        summary.squares += class.orbit_size;
+        summary.isomorphism_classes += 1;
+        if class.representative.identity().is_some() {
+            summary.loops += 1;
+        }
+        summary.automorphisms.insert(class.automorphisms);
+    }
+
+    summaries.into_values().collect()
+}
+
+// Summarizes the main classes of order n by joining the isotopy classes of summarize_isotopy_classes.
+// Paratopic squares have the same number of autotopisms, autoparatopisms and transversals. Sorted by canonical form.
+pub fn summarize_main_classes(classes: &[IsomorphismClass], threads: usize) -> Vec<ClassSummary> {
+    let mut summaries: BTreeMap<LatinSquare, ClassSummary> = BTreeMap::new();
+
+    for isotopy_class in summarize_isotopy_classes(classes, threads) {
+        let canonical_form = isotopy_class.canonical_form.main_class_canonical_form();
+
+        match summaries.get_mut(&canonical_form) {
+            Some(summary) => {
+                summary.squares += isotopy_class.squares;
+                summary.isomorphism_classes += isotopy_class.isomorphism_classes;
+                summary.isotopy_classes += 1;
+                summary.loops += isotopy_class.loops;
+                summary.automorphisms.extend(isotopy_class.automorphisms);
+            }
+            None => {
+                summaries.insert(
+                    canonical_form.clone(),
+                    ClassSummary {
+                        canonical_form,
+                        ..isotopy_class
+                    },
+                );
+            }
+        }
+    }
+
+    summaries.into_values().collect()
+}
+
+// Sorts the classifications like LatinSquare::generate_all and sets the indices accordingly.
+fn sort_and_index(classifications: &mut [LatinSquareClassification]) {
+    classifications.sort_by(|a, b| a.square.cmp(&b.square));
+
+    for (i, r) in classifications.iter_mut().enumerate() {
+        r.index = i;
+    }
+}
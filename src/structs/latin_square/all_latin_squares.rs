@@ -0,0 +1,99 @@
+use super::LatinSquare;
+
+// Iterates over all latin squares of order n in lexicographic order, the same order as LatinSquare::generate_all.
+// The cells are filled in row-major order with an explicit backtracking stack, so only the current square is stored.
+pub struct AllLatinSquares {
+    n: usize,
+    // The values of the filled cells, in row-major order.
+    cells: Vec<usize>,
+    // row_used[r][s] and column_used[c][s] are true if s is already used in row r or column c.
+    row_used: Vec<Vec<bool>>,
+    column_used: Vec<Vec<bool>>,
+    started: bool,
+    done: bool,
+}
+
+impl AllLatinSquares {
+    pub fn new(n: usize) -> AllLatinSquares {
+        AllLatinSquares {
+            n,
+            cells: vec![],
+            row_used: vec![vec![false; n]; n],
+            column_used: vec![vec![false; n]; n],
+            started: false,
+            done: n == 0,
+        }
+    }
+
+    fn place(&mut self, v: usize) {
+        let cell = self.cells.len();
+        let (row, col) = (cell / self.n, cell % self.n);
+
+        self.row_used[row][v] = true;
+        self.column_used[col][v] = true;
+        self.cells.push(v);
+    }
+
+    fn remove(&mut self) -> usize {
+        let v = self.cells.pop().unwrap();
+        let cell = self.cells.len();
+        let (row, col) = (cell / self.n, cell % self.n);
+
+        self.row_used[row][v] = false;
+        self.column_used[col][v] = false;
+
+        v
+    }
+
+    // Fills the remaining cells, starting with a value of at least start in the next cell.
+    // Backtracks when no value fits, and returns false when there is nothing left to backtrack.
+    fn advance(&mut self, mut start: usize) -> bool {
+        let n = self.n;
+
+        while self.cells.len() < n * n {
+            let cell = self.cells.len();
+            let (row_used, column_used) = (&self.row_used[cell / n], &self.column_used[cell % n]);
+
+            match (start..n).find(|v| !row_used[*v] && !column_used[*v]) {
+                Some(v) => {
+                    self.place(v);
+                    start = 0;
+                }
+                None => {
+                    if self.cells.is_empty() {
+                        return false;
+                    }
+                    start = self.remove() + 1;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Iterator for AllLatinSquares {
+    type Item = LatinSquare;
+
+    fn next(&mut self) -> Option<LatinSquare> {
+        if self.done {
+            return None;
+        }
+
+        let start = if self.started {
+            self.remove() + 1
+        } else {
+            self.started = true;
+            0
+        };
+
+        if !self.advance(start) {
+            self.done = true;
+            return None;
+        }
+
+        Some(LatinSquare(
+            self.cells.chunks(self.n).map(|row| row.to_vec()).collect(),
+        ))
+    }
+}
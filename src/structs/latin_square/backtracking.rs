@@ -0,0 +1,56 @@
+// Fills a latin square whose first row and column are 0, 1, ..., n - 1, one cell at a time in row-major order,
+// and calls found for every completed square. row_used[r][s] and column_used[c][s] are true if s is already
+// used in row r or column c.
+fn reduced_latin_square_recursion(
+    n: usize,
+    cell: usize,
+    rows: &mut Vec<Vec<usize>>,
+    row_used: &mut [Vec<bool>],
+    column_used: &mut [Vec<bool>],
+    found: &mut dyn FnMut(&[Vec<usize>]),
+) {
+    if cell == n * n {
+        found(rows);
+        return;
+    }
+
+    let (row, col) = (cell / n, cell % n);
+
+    // The first row and column are fixed.
+    if row == 0 || col == 0 {
+        reduced_latin_square_recursion(n, cell + 1, rows, row_used, column_used, found);
+        return;
+    }
+
+    for i in 0..n {
+        if row_used[row][i] || column_used[col][i] {
+            continue;
+        }
+
+        rows[row][col] = i;
+        row_used[row][i] = true;
+        column_used[col][i] = true;
+
+        reduced_latin_square_recursion(n, cell + 1, rows, row_used, column_used, found);
+
+        row_used[row][i] = false;
+        column_used[col][i] = false;
+    }
+}
+
+// Calls found for every reduced latin square, i.e. with first row and column in natural order,
+// in lexicographic order.
+pub fn for_each_reduced_latin_square(n: usize, found: &mut dyn FnMut(&[Vec<usize>])) {
+    let mut rows: Vec<Vec<usize>> = vec![vec![0; n]; n];
+    let mut row_used: Vec<Vec<bool>> = vec![vec![false; n]; n];
+    let mut column_used: Vec<Vec<bool>> = vec![vec![false; n]; n];
+
+    for i in 0..n {
+        rows[0][i] = i;
+        rows[i][0] = i;
+        row_used[i][i] = true;
+        column_used[i][i] = true;
+    }
+
+    reduced_latin_square_recursion(n, 0, &mut rows, &mut row_used, &mut column_used, found);
+}
@@ -0,0 +1,151 @@
+use std::cmp::Ordering;
+
+use super::LatinSquare;
+use super::Permutation;
+
+// Compares the first `rows` rows of the conjugate square[σ^-1 i][σ^-1 j] -> σ(.) with the first rows of square.
+// Only meaningful if σ maps {0, ..., rows - 1} onto itself, since then these rows are fully known.
+fn compare_conjugate_prefix(
+    square: &[Vec<usize>],
+    sigma: &[usize],
+    sigma_inverse: &[usize],
+    rows: usize,
+) -> Ordering {
+    let n = sigma.len();
+
+    for i in 0..rows {
+        for j in 0..n {
+            let v = sigma[square[sigma_inverse[i]][sigma_inverse[j]]];
+
+            match v.cmp(&square[i][j]) {
+                Ordering::Equal => continue,
+                o => return o,
+            }
+        }
+    }
+
+    Ordering::Equal
+}
+
+// The permutations (and their inverses) that map {0, ..., k} onto itself, for every k.
+fn prefix_preserving_permutations(n: usize) -> Vec<Vec<(Vec<usize>, Vec<usize>)>> {
+    let perms = Permutation::generate_all(n);
+
+    let mut result: Vec<Vec<(Vec<usize>, Vec<usize>)>> = vec![vec![]; n];
+
+    for p in perms.iter() {
+        let mut inverse = vec![0; n];
+        for (i, v) in p.0.iter().enumerate() {
+            inverse[*v] = i;
+        }
+
+        for (k, r) in result.iter_mut().enumerate() {
+            if p.0.iter().take(k + 1).all(|&v| v <= k) {
+                r.push((p.0.clone(), inverse.clone()));
+            }
+        }
+    }
+
+    result
+}
+
+struct Search {
+    n: usize,
+    rows: Vec<Vec<usize>>,
+    // row_used[s] is true if the symbol s already appears in the row being filled, and column_used[c][s] if it
+    // appears in column c.
+    row_used: Vec<bool>,
+    column_used: Vec<Vec<bool>>,
+    preserving: Vec<Vec<(Vec<usize>, Vec<usize>)>>,
+    result: Vec<(LatinSquare, usize)>,
+}
+
+impl Search {
+    // Returns None if some conjugate is smaller than the current rows, otherwise the number of
+    // permutations that fix the current rows.
+    fn check_canonical(&self) -> Option<usize> {
+        let k = self.rows.len() - 1;
+
+        let mut fixing: usize = 0;
+
+        for (sigma, sigma_inverse) in self.preserving[k].iter() {
+            match compare_conjugate_prefix(&self.rows, sigma, sigma_inverse, k + 1) {
+                Ordering::Less => return None,
+                Ordering::Equal => fixing += 1,
+                Ordering::Greater => (),
+            }
+        }
+
+        Some(fixing)
+    }
+
+    fn fill_row(&mut self, row: &mut Vec<usize>) {
+        let n = self.n;
+        let column = row.len();
+
+        if column == n {
+            self.rows.push(row.clone());
+
+            if let Some(fixing) = self.check_canonical() {
+                if self.rows.len() == n {
+                    self.result.push((LatinSquare(self.rows.clone()), fixing));
+                } else {
+                    self.next_row();
+                }
+            }
+
+            self.rows.pop();
+            return;
+        }
+
+        for s in 0..n {
+            if self.row_used[s] || self.column_used[column][s] {
+                continue;
+            }
+
+            row.push(s);
+            self.row_used[s] = true;
+
+            self.fill_row(row);
+
+            self.row_used[s] = false;
+            row.pop();
+        }
+    }
+
+    fn next_row(&mut self) {
+        let k = self.rows.len() - 1;
+
+        for (c, v) in self.rows[k].iter().enumerate() {
+            self.column_used[c][*v] = true;
+        }
+
+        // The next row starts with every symbol unused.
+        let row_used = std::mem::replace(&mut self.row_used, vec![false; self.n]);
+        self.fill_row(&mut vec![]);
+        self.row_used = row_used;
+
+        for (c, v) in self.rows[k].iter().enumerate() {
+            self.column_used[c][*v] = false;
+        }
+    }
+}
+
+// Finds the lexicographically smallest square of every isomorphism class, together with the number
+// of automorphisms of that square. Squares are built row by row, and a partial square is discarded
+// as soon as a permutation mapping {0, ..., k} onto itself gives a smaller conjugate of its first k + 1 rows,
+// since every completion then has a smaller conjugate as well.
+pub fn isomorphism_class_representatives(n: usize) -> Vec<(LatinSquare, usize)> {
+    let mut search = Search {
+        n,
+        rows: vec![],
+        row_used: vec![false; n],
+        column_used: vec![vec![false; n]; n],
+        preserving: prefix_preserving_permutations(n),
+        result: vec![],
+    };
+
+    search.fill_row(&mut vec![]);
+
+    search.result
+}
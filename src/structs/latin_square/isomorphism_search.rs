@@ -0,0 +1,575 @@
+use std::cmp::Ordering;
+
+use super::Isotopism;
+use super::LatinSquare;
+use super::Permutation;
+
+// Properties of an element that any isomorphism preserves: the cycle types of the left and
+// right multiplication by x, and whether xx = x.
+fn element_invariant(square: &LatinSquare, x: usize) -> (Vec<usize>, Vec<usize>, bool) {
+    let left = square.addition_permutation(x, &super::Sidedness::Left);
+    let right = square.addition_permutation(x, &super::Sidedness::Right);
+
+    (left.cycle_type(), right.cycle_type(), square.0[x][x] == x)
+}
+
+// The elements generated by the given elements, i.e. the smallest subquasigroup containing them, in the order
+// they are found. In a finite quasigroup it is enough to close under multiplication.
+fn closure(square: &LatinSquare, elements: &[usize]) -> Vec<usize> {
+    let mut contained: Vec<bool> = vec![false; square.0.len()];
+    let mut found: Vec<usize> = vec![];
+
+    for x in elements {
+        if !contained[*x] {
+            contained[*x] = true;
+            found.push(*x);
+        }
+    }
+
+    let mut k = 0;
+
+    while k < found.len() {
+        let a = found[k];
+
+        for i in 0..=k {
+            let b = found[i];
+
+            for z in [square.0[a][b], square.0[b][a]] {
+                if !contained[z] {
+                    contained[z] = true;
+                    found.push(z);
+                }
+            }
+        }
+
+        k += 1;
+    }
+
+    found
+}
+
+// A set of elements generating the quasigroup, chosen greedily by always adding the smallest element
+// that is not yet generated.
+pub fn generating_set(square: &LatinSquare) -> Vec<usize> {
+    let n = square.0.len();
+
+    let mut generators: Vec<usize> = vec![];
+    let mut generated: Vec<usize> = vec![];
+
+    while let Some(x) = (0..n).find(|x| !generated.contains(x)) {
+        generators.push(x);
+        generated = closure(square, &generators);
+    }
+
+    generators
+}
+
+// Extends the partial map f with f(xy) = f(x)f(y) for every pair of mapped elements, until nothing new is found.
+// Returns false if this contradicts the map or makes it non-injective.
+fn extend(square: &LatinSquare, target: &LatinSquare, map: &mut [Option<usize>]) -> bool {
+    let n = square.0.len();
+
+    let mut used: Vec<bool> = vec![false; n];
+    let mut known: Vec<usize> = vec![];
+
+    for (x, image) in map.iter().enumerate() {
+        if let Some(y) = image {
+            used[*y] = true;
+            known.push(x);
+        }
+    }
+
+    let mut k = 0;
+
+    while k < known.len() {
+        let a = known[k];
+
+        for i in 0..=k {
+            let b = known[i];
+
+            for (x, y) in [(a, b), (b, a)] {
+                let z = square.0[x][y];
+                let image = target.0[map[x].unwrap()][map[y].unwrap()];
+
+                match map[z] {
+                    Some(w) if w != image => return false,
+                    Some(_) => (),
+                    None => {
+                        if used[image] {
+                            return false;
+                        }
+                        used[image] = true;
+                        map[z] = Some(image);
+                        known.push(z);
+                    }
+                }
+            }
+        }
+
+        k += 1;
+    }
+
+    true
+}
+
+// Finds the permutations p with p(xy) = p(x)p(y), where the left side is computed in square and the right side
+// in target. Such a p is determined by its values on a generating set, so only the images of the generators are
+// searched, and only among elements with the same invariants. Stops after the first one if first_only is set.
+pub fn isomorphisms(
+    square: &LatinSquare,
+    target: &LatinSquare,
+    first_only: bool,
+) -> Vec<Permutation> {
+    let n = square.0.len();
+
+    if target.0.len() != n {
+        return vec![];
+    }
+
+    let generators = generating_set(square);
+
+    let target_invariants: Vec<_> = (0..n).map(|x| element_invariant(target, x)).collect();
+
+    // The possible images of every generator.
+    let candidates: Vec<Vec<usize>> = generators
+        .iter()
+        .map(|g| {
+            let invariant = element_invariant(square, *g);
+            (0..n)
+                .filter(|y| target_invariants[*y] == invariant)
+                .collect()
+        })
+        .collect();
+
+    let mut result: Vec<Permutation> = vec![];
+
+    search(
+        square,
+        target,
+        &generators,
+        &candidates,
+        vec![None; n],
+        first_only,
+        &mut result,
+    );
+
+    result
+}
+
+fn search(
+    square: &LatinSquare,
+    target: &LatinSquare,
+    generators: &[usize],
+    candidates: &[Vec<usize>],
+    map: Vec<Option<usize>>,
+    first_only: bool,
+    result: &mut Vec<Permutation>,
+) {
+    let level = generators.len() - candidates.len();
+
+    let Some((images, remaining)) = candidates.split_first() else {
+        // The generators generate everything, so the map is complete.
+        result.push(Permutation(map.into_iter().map(|x| x.unwrap()).collect()));
+        return;
+    };
+
+    for image in images {
+        if first_only && !result.is_empty() {
+            return;
+        }
+
+        if map.contains(&Some(*image)) {
+            continue;
+        }
+
+        let mut next = map.clone();
+        next[generators[level]] = Some(*image);
+
+        if extend(square, target, &mut next) {
+            search(
+                square, target, generators, remaining, next, first_only, result,
+            );
+        }
+    }
+}
+
+// The permutations b with b p b^-1 = q, i.e. b(p(x)) = q(b(x)). Every cycle of p is mapped onto a cycle of q
+// of the same length, starting at any of its points.
+fn conjugating_permutations(p: &Permutation, q: &Permutation) -> Vec<Permutation> {
+    let mut result: Vec<Permutation> = vec![];
+
+    if p.cycle_type() != q.cycle_type() {
+        return result;
+    }
+
+    let n = p.0.len();
+
+    map_cycles(q, &p.cycles(), vec![None; n], &mut result);
+
+    result
+}
+
+fn map_cycles(
+    q: &Permutation,
+    cycles: &[Vec<usize>],
+    map: Vec<Option<usize>>,
+    result: &mut Vec<Permutation>,
+) {
+    let Some((cycle, remaining)) = cycles.split_first() else {
+        result.push(Permutation(map.into_iter().map(|x| x.unwrap()).collect()));
+        return;
+    };
+
+    for start in 0..q.0.len() {
+        // The points of q that are already images lie in whole cycles.
+        if map.contains(&Some(start)) {
+            continue;
+        }
+
+        let mut length = 1;
+        let mut image = q.0[start];
+
+        while image != start {
+            image = q.0[image];
+            length += 1;
+        }
+
+        if length != cycle.len() {
+            continue;
+        }
+
+        let mut next = map.clone();
+
+        for x in cycle {
+            next[*x] = Some(image);
+            image = q.0[image];
+        }
+
+        map_cycles(q, remaining, next, result);
+    }
+}
+
+// For every row i, the sorted cycle types of r_i^-1 r_k for the other rows k. An isotopism conjugates these by
+// the column permutation, so a row can only be mapped to a row with the same cycle types.
+fn row_quotient_types(rows: &[Permutation]) -> Vec<Vec<Vec<usize>>> {
+    rows.iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let inverse = r.inverse();
+
+            let mut types: Vec<Vec<usize>> = rows
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k != i)
+                .map(|(_, s)| inverse.compose(s).cycle_type())
+                .collect();
+
+            types.sort();
+
+            types
+        })
+        .collect()
+}
+
+// The number of permutations commuting with a permutation of the given cycle type, i.e. the product of
+// l^m m! over the cycle lengths l appearing m times.
+fn centralizer_order(cycle_type: &[usize]) -> usize {
+    let mut order: usize = 1;
+    let mut i = 0;
+
+    while i < cycle_type.len() {
+        let l = cycle_type[i];
+        let m = cycle_type[i..].iter().take_while(|x| **x == l).count();
+
+        order *= l.pow(m as u32) * crate::common::factorial(m);
+        i += m;
+    }
+
+    order
+}
+
+// Finds the isotopisms (a, b, c) from source to target, i.e. with target[a(i)][b(j)] = c(source[i][j]).
+// With the rows r_i(j) = source[i][j] and s_k(j) = target[k][j], taking i = 0 gives c = s_a(0) b r_0^-1,
+// and then the condition becomes b r_0^-1 r_i b^-1 = s_a(0)^-1 s_a(i) for every i. So only a(0), one a(i)
+// and the permutations b conjugating r_0^-1 r_i are searched. Stops after the first one if first_only is set.
+pub fn isotopisms(source: &LatinSquare, target: &LatinSquare, first_only: bool) -> Vec<Isotopism> {
+    let n = source.0.len();
+
+    if target.0.len() != n || n == 0 {
+        return vec![];
+    }
+
+    let identity = Permutation((0..n).collect());
+
+    if n == 1 {
+        return vec![(identity.clone(), identity.clone(), identity)];
+    }
+
+    let source_rows: Vec<Permutation> = source.0.iter().map(|r| Permutation(r.clone())).collect();
+    let target_rows: Vec<Permutation> = target.0.iter().map(|r| Permutation(r.clone())).collect();
+
+    let source_types = row_quotient_types(&source_rows);
+    let target_types = row_quotient_types(&target_rows);
+
+    let mut sorted_source_types = source_types.clone();
+    let mut sorted_target_types = target_types.clone();
+    sorted_source_types.sort();
+    sorted_target_types.sort();
+
+    if sorted_source_types != sorted_target_types {
+        return vec![];
+    }
+
+    let first_inverse = source_rows[0].inverse();
+    let quotients: Vec<Permutation> = source_rows
+        .iter()
+        .map(|r| first_inverse.compose(r))
+        .collect();
+
+    // The row whose quotient has the fewest permutations conjugating it, to search as few b as possible.
+    let pivot = (1..n)
+        .min_by_key(|i| centralizer_order(&quotients[*i].cycle_type()))
+        .unwrap();
+
+    // row_with[j][v] is the row of target with v in column j.
+    let mut row_with: Vec<Vec<usize>> = vec![vec![0; n]; n];
+
+    for (k, row) in target.0.iter().enumerate() {
+        for (j, v) in row.iter().enumerate() {
+            row_with[j][*v] = k;
+        }
+    }
+
+    let mut result: Vec<Isotopism> = vec![];
+
+    for a in (0..n).filter(|a| target_types[*a] == source_types[0]) {
+        let a_inverse = target_rows[a].inverse();
+
+        for k in 0..n {
+            if k == a || target_types[k] != source_types[pivot] {
+                continue;
+            }
+
+            let q = a_inverse.compose(&target_rows[k]);
+
+            'conjugates: for b in conjugating_permutations(&quotients[pivot], &q) {
+                let c = target_rows[a].compose(&b).compose(&first_inverse);
+
+                // Row i must go to the row with c(source[i][0]) in column b(0), and then the whole row must match.
+                let mut rows: Vec<usize> = vec![];
+
+                for row in source.0.iter() {
+                    let k = row_with[b.0[0]][c.0[row[0]]];
+
+                    if (0..n).any(|j| target.0[k][b.0[j]] != c.0[row[j]]) {
+                        continue 'conjugates;
+                    }
+
+                    rows.push(k);
+                }
+
+                result.push((Permutation(rows), b, c));
+
+                if first_only {
+                    return result;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+// The permutation with the given cycle lengths, taken in increasing order, on consecutive points,
+// e.g. [2, 3] gives (0 1)(2 3 4). It is the lexicographically smallest permutation of its cycle type
+// without fixed points.
+fn smallest_with_cycle_type(cycle_type: &[usize]) -> Permutation {
+    let mut lengths = cycle_type.to_vec();
+    lengths.sort();
+
+    let mut result: Vec<usize> = vec![];
+
+    for l in lengths {
+        let start = result.len();
+        result.extend(start + 1..start + l);
+        result.push(start);
+    }
+
+    Permutation(result)
+}
+
+// The lexicographically smallest square isotopic to the square. It is reduced, so it is found among the
+// reduced isotopes, given by a row r moved to the top and a column permutation b. These have the rows
+// b r_r^-1 r_i b^-1, each placed at the row given by its value in column 0. The row starting with 1 has to
+// be the smallest permutation of the smallest cycle type among the r_r^-1 r_i, so only the pairs (r, i) with
+// that cycle type and the b conjugating r_r^-1 r_i to it are searched.
+pub fn canonical_isotope(square: &LatinSquare) -> LatinSquare {
+    let n = square.0.len();
+
+    if n <= 1 {
+        return square.clone();
+    }
+
+    let rows: Vec<Permutation> = square.0.iter().map(|r| Permutation(r.clone())).collect();
+
+    // The quotients r_r^-1 r_i for every r, with the cycle lengths in increasing order.
+    let quotients: Vec<Vec<(Vec<usize>, Permutation)>> = rows
+        .iter()
+        .map(|r| {
+            let inverse = r.inverse();
+
+            rows.iter()
+                .map(|s| {
+                    let quotient = inverse.compose(s);
+                    let mut cycle_type = quotient.cycle_type();
+                    cycle_type.reverse();
+
+                    (cycle_type, quotient)
+                })
+                .collect()
+        })
+        .collect();
+
+    let smallest_type = (0..n)
+        .flat_map(|r| (0..n).filter(move |i| *i != r).map(move |i| (r, i)))
+        .map(|(r, i)| &quotients[r][i].0)
+        .min()
+        .unwrap()
+        .clone();
+
+    let target = smallest_with_cycle_type(&smallest_type);
+
+    // row_with[j][v] is the row with v in column j.
+    let mut row_with: Vec<Vec<usize>> = vec![vec![0; n]; n];
+
+    for (i, row) in square.0.iter().enumerate() {
+        for (j, v) in row.iter().enumerate() {
+            row_with[j][*v] = i;
+        }
+    }
+
+    let mut best: Option<LatinSquare> = None;
+
+    for (r, row_quotients) in quotients.iter().enumerate() {
+        for (i, (cycle_type, quotient)) in row_quotients.iter().enumerate() {
+            if i == r || *cycle_type != smallest_type {
+                continue;
+            }
+
+            'conjugates: for b in conjugating_permutations(quotient, &target) {
+                let b_inverse = b.inverse();
+
+                // Builds the candidate a row at a time, and gives up as soon as it is larger than the best one.
+                // Row k is b r_r^-1 r_i b^-1 for the row i with r_r^-1 r_i (b^-1(0)) = b^-1(k), i.e. the row i
+                // with square[i][b^-1(0)] = square[r][b^-1(k)].
+                let mut candidate: Vec<Vec<usize>> = vec![];
+                let mut smaller = best.is_none();
+
+                for k in 0..n {
+                    let i = row_with[b_inverse.0[0]][square.0[r][b_inverse.0[k]]];
+                    let q = &row_quotients[i].1;
+                    let row: Vec<usize> = (0..n).map(|j| b.0[q.0[b_inverse.0[j]]]).collect();
+
+                    if !smaller {
+                        match row.cmp(&best.as_ref().unwrap().0[k]) {
+                            Ordering::Greater => continue 'conjugates,
+                            Ordering::Less => smaller = true,
+                            Ordering::Equal => (),
+                        }
+                    }
+
+                    candidate.push(row);
+                }
+
+                if smaller {
+                    best = Some(LatinSquare(candidate));
+                }
+            }
+        }
+    }
+
+    best.unwrap()
+}
+
+// A canonical labelling p of the square, i.e. square.apply_permutation(p) is the same for all isomorphic squares.
+// Among the shortest sequences of elements generating the quasigroup, only those with the smallest invariants are
+// used. Each of them labels the elements in the order closure finds them, and the labelling giving the smallest
+// square is chosen. Isomorphisms map these sequences to each other, so the result does not depend on the labels.
+pub fn canonical_labelling(square: &LatinSquare) -> Permutation {
+    let n = square.0.len();
+
+    let invariants: Vec<_> = (0..n).map(|x| element_invariant(square, x)).collect();
+
+    let mut length = 1;
+
+    let sequences = loop {
+        let mut generating: Vec<Vec<usize>> = vec![];
+        sequences_of_length(n, length, &mut vec![], &mut |s| {
+            if closure(square, s).len() == n {
+                generating.push(s.to_vec());
+            }
+        });
+
+        if !generating.is_empty() {
+            break generating;
+        }
+
+        length += 1;
+    };
+
+    let smallest_invariants = sequences
+        .iter()
+        .map(|s| s.iter().map(|x| &invariants[*x]).collect::<Vec<_>>())
+        .min()
+        .unwrap();
+
+    let mut best: Option<(LatinSquare, Permutation)> = None;
+
+    for sequence in sequences.iter() {
+        if sequence
+            .iter()
+            .map(|x| &invariants[*x])
+            .ne(smallest_invariants.iter().copied())
+        {
+            continue;
+        }
+
+        // The element found t-th gets the label t.
+        let mut labelling: Vec<usize> = vec![0; n];
+
+        for (t, x) in closure(square, sequence).into_iter().enumerate() {
+            labelling[x] = t;
+        }
+
+        let p = Permutation(labelling);
+        let mut labelled = square.clone();
+        labelled.apply_permutation(p.clone());
+
+        if best.as_ref().is_none_or(|(s, _)| labelled < *s) {
+            best = Some((labelled, p));
+        }
+    }
+
+    best.unwrap().1
+}
+
+// Calls found with every sequence of distinct elements of the given length.
+fn sequences_of_length(
+    n: usize,
+    length: usize,
+    sequence: &mut Vec<usize>,
+    found: &mut dyn FnMut(&[usize]),
+) {
+    if sequence.len() == length {
+        found(sequence);
+        return;
+    }
+
+    for x in 0..n {
+        if !sequence.contains(&x) {
+            sequence.push(x);
+            sequences_of_length(n, length, sequence, found);
+            sequence.pop();
+        }
+    }
+}
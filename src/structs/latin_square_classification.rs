@@ -3,6 +3,7 @@ use super::Bits;
 use super::LatinSquare;
 use super::LatinStructure;
 use super::LatinType;
+use super::Parastrophy;
 
 #[derive(Clone)]
 pub struct LatinSquareClassification {
@@ -10,6 +11,11 @@ pub struct LatinSquareClassification {
     pub index: usize,
     pub square: LatinSquare,
     pub all_permutations_all_affine_automorphisms: Vec<AllAffineAutomorphisms>,
+    pub autotopisms: usize,
+    pub autoparatopisms: usize,
+    pub transversals: usize,
+    // The parastrophies whose conjugate is the square itself.
+    pub conjugate_symmetry: Vec<Parastrophy>,
 }
 
 impl LatinSquareClassification {
@@ -0,0 +1,207 @@
+use super::ExactCover;
+use super::LatinSquare;
+use super::PartialLatinSquare;
+
+// The symbol s in the cell (r, c), as (r, c, s).
+type Placement = (usize, usize, usize);
+
+// The latin squares of order n as an exact cover problem. Every option places a symbol s in a cell (r, c),
+// and covers the cell, the symbol in row r and the symbol in column c, so a solution is exactly a latin square.
+// More constraints can be added before searching, e.g. fixed cells, a fixed identity or symmetry.
+#[derive(Debug, Clone)]
+pub struct LatinSquareCover {
+    n: usize,
+    // Whether every symbol is allowed in every cell, as allowed[r][c][s].
+    allowed: Vec<Vec<Vec<bool>>>,
+    // Extra columns given by the placements (r, c, s) covering them, and whether they must be covered.
+    constraints: Vec<(Vec<Placement>, bool)>,
+    // Whether the placements (r, c, s) and (c, r, s) are made together.
+    symmetric: bool,
+}
+
+impl LatinSquareCover {
+    pub fn new(n: usize) -> LatinSquareCover {
+        LatinSquareCover {
+            n,
+            allowed: vec![vec![vec![true; n]; n]; n],
+            constraints: vec![],
+            symmetric: false,
+        }
+    }
+
+    // The completions of the partial latin square.
+    pub fn from_partial(partial: &PartialLatinSquare) -> LatinSquareCover {
+        let mut cover = LatinSquareCover::new(partial.order());
+
+        for (r, row) in partial.0.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                if let Some(s) = cell {
+                    cover.fix(r, c, *s);
+                }
+            }
+        }
+
+        cover
+    }
+
+    pub fn order(&self) -> usize {
+        self.n
+    }
+
+    // Only allows the symbol s in the cell. A symbol that is too large leaves no symbol for the cell.
+    pub fn fix(&mut self, r: usize, c: usize, s: usize) {
+        for (t, allowed) in self.allowed[r][c].iter_mut().enumerate() {
+            *allowed = t == s;
+        }
+    }
+
+    pub fn forbid(&mut self, r: usize, c: usize, s: usize) {
+        if s < self.n {
+            self.allowed[r][c][s] = false;
+        }
+    }
+
+    // Makes e the identity, i.e. fixes row e and column e to 0, 1, ..., n - 1. With e = 0 these are the reduced
+    // latin squares, the "sudocurity" squares of the old abelian group code.
+    pub fn fix_identity(&mut self, e: usize) {
+        for x in 0..self.n {
+            self.fix(e, x, x);
+            self.fix(x, e, x);
+        }
+    }
+
+    // Adds a constraint that exactly one of the placements is made, or at most one if exactly_once is false.
+    pub fn add_constraint(&mut self, placements: Vec<Placement>, exactly_once: bool) {
+        self.constraints.push((placements, exactly_once));
+    }
+
+    // Only allows squares equal to their transpose.
+    pub fn symmetric(&mut self) {
+        self.symmetric = true;
+    }
+
+    // The exact cover problem, together with the placements made by every row of it.
+    fn exact_cover(&self) -> (ExactCover, Vec<Vec<Placement>>) {
+        let n = self.n;
+
+        // The cells, the symbols in the rows and the symbols in the columns, followed by the extra constraints.
+        let cell = |r: usize, c: usize| r * n + c;
+        let row_symbol = |r: usize, s: usize| n * n + r * n + s;
+        let column_symbol = |c: usize, s: usize| 2 * n * n + c * n + s;
+
+        let mut primary: Vec<usize> = vec![];
+        let mut secondary: Vec<usize> = vec![];
+
+        for (i, (_, exactly_once)) in self.constraints.iter().enumerate() {
+            if *exactly_once {
+                primary.push(i);
+            } else {
+                secondary.push(i);
+            }
+        }
+
+        // The column of every extra constraint.
+        let mut constraint_columns: Vec<usize> = vec![0; self.constraints.len()];
+        for (k, i) in primary.iter().chain(secondary.iter()).enumerate() {
+            constraint_columns[*i] = 3 * n * n + k;
+        }
+
+        let mut cover = ExactCover::new(3 * n * n + primary.len(), secondary.len());
+        let mut placements: Vec<Vec<Placement>> = vec![];
+
+        for r in 0..n {
+            for c in 0..n {
+                if self.symmetric && c < r {
+                    continue;
+                }
+
+                for s in (0..n).filter(|s| self.allowed[r][c][*s]) {
+                    let option: Vec<Placement> = if self.symmetric && r != c {
+                        if !self.allowed[c][r][s] {
+                            continue;
+                        }
+                        vec![(r, c, s), (c, r, s)]
+                    } else {
+                        vec![(r, c, s)]
+                    };
+
+                    let mut columns: Vec<usize> = vec![];
+
+                    for (r, c, s) in option.iter() {
+                        columns.push(cell(*r, *c));
+                        columns.push(row_symbol(*r, *s));
+                        columns.push(column_symbol(*c, *s));
+                    }
+
+                    for (i, (constraint, _)) in self.constraints.iter().enumerate() {
+                        for p in option.iter() {
+                            if constraint.contains(p) {
+                                columns.push(constraint_columns[i]);
+                            }
+                        }
+                    }
+
+                    // An option meeting a constraint twice can never be used.
+                    columns.sort();
+                    if columns.windows(2).any(|w| w[0] == w[1]) {
+                        continue;
+                    }
+
+                    cover.add_row(&columns);
+                    placements.push(option);
+                }
+            }
+        }
+
+        (cover, placements)
+    }
+
+    // Calls found for every latin square satisfying the constraints, until found returns false.
+    pub fn for_each_square(&self, found: &mut dyn FnMut(LatinSquare) -> bool) {
+        let n = self.n;
+        let (mut cover, placements) = self.exact_cover();
+
+        cover.for_each_solution(&mut |rows| {
+            let mut square: Vec<Vec<usize>> = vec![vec![0; n]; n];
+
+            for row in rows {
+                for (r, c, s) in placements[*row].iter() {
+                    square[*r][*c] = *s;
+                }
+            }
+
+            found(LatinSquare(square))
+        });
+    }
+
+    pub fn first(&self) -> Option<LatinSquare> {
+        let mut result: Option<LatinSquare> = None;
+
+        self.for_each_square(&mut |square| {
+            result = Some(square);
+            false
+        });
+
+        result
+    }
+
+    // Every square satisfying the constraints, sorted.
+    pub fn squares(&self) -> Vec<LatinSquare> {
+        let mut result: Vec<LatinSquare> = vec![];
+
+        self.for_each_square(&mut |square| {
+            result.push(square);
+            true
+        });
+
+        result.sort();
+
+        result
+    }
+
+    pub fn count(&self) -> usize {
+        let (mut cover, _) = self.exact_cover();
+
+        cover.count_solutions()
+    }
+}
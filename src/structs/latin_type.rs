@@ -1,13 +1,58 @@
 use std::fmt::Display;
 
 use super::LatinStructure;
+use super::Permutation;
+use super::QuasigroupProperties;
 
 #[derive(Clone)]
 pub struct LatinType {
     pub structure: LatinStructure,
-    pub left_identity: bool,
-    pub right_identity: bool,
+    // The element e with ex = x for all x.
+    pub left_identity: Option<usize>,
+    // The element e with xe = x for all x.
+    pub right_identity: Option<usize>,
+    // Set exactly when both of the one-sided identities exist, which are then the same element.
+    pub identity: Option<usize>,
     pub commutative: bool,
+    pub properties: QuasigroupProperties,
+}
+
+impl LatinType {
+    // The type of the square after LatinSquare::apply_permutation(p). Only the identity elements change.
+    pub fn apply_permutation(&mut self, p: &Permutation) {
+        self.left_identity = self.left_identity.map(|e| p.0[e]);
+        self.right_identity = self.right_identity.map(|e| p.0[e]);
+        self.identity = self.identity.map(|e| p.0[e]);
+    }
+
+    // The lines describing the latin square below its structure.
+    pub fn additional_rows(&self) -> Vec<String> {
+        let mut rows: Vec<String> = vec![];
+
+        match self.structure {
+            LatinStructure::Loop | LatinStructure::Quasigroup if self.commutative => {
+                rows.push("Commutative".to_string())
+            }
+            _ => (),
+        }
+
+        if let Some(e) = self.identity {
+            rows.push(format!("Identity: {}", e));
+        } else {
+            if let Some(e) = self.left_identity {
+                rows.push(format!("Left Identity: {}", e));
+            }
+            if let Some(e) = self.right_identity {
+                rows.push(format!("Right Identity: {}", e));
+            }
+        }
+
+        for name in self.properties.names() {
+            rows.push(name.to_string());
+        }
+
+        rows
+    }
 }
 
 impl Display for LatinType {
@@ -16,27 +61,10 @@ impl Display for LatinType {
 
         text.push_str(&self.structure.to_string());
 
-        text.push_str(match self.structure {
-            LatinStructure::Loop => {
-                if self.commutative {
-                    "\nCommutative"
-                } else {
-                    ""
-                }
-            }
-            LatinStructure::Quasigroup => {
-                if self.commutative {
-                    "\nCommutative"
-                } else if self.left_identity {
-                    "\nLeft Identity"
-                } else if self.right_identity {
-                    "\nRight Identity"
-                } else {
-                    ""
-                }
-            }
-            _ => "",
-        });
+        for row in self.additional_rows() {
+            text.push('\n');
+            text.push_str(&row);
+        }
 
         write!(f, "{}", &text)
     }
@@ -0,0 +1,87 @@
+use std::fmt::Display;
+
+use super::finite_field;
+use super::FiniteField;
+use super::LatinSquare;
+use super::PartialLatinSquare;
+
+// Something that keeps a set of latin squares from being mutually orthogonal. The squares are numbered by their
+// position in the set.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MolsError {
+    // The square does not have the same order as the first one.
+    DifferentOrder(usize),
+    NotLatin(usize),
+    NotOrthogonal(usize, usize),
+}
+
+impl Display for MolsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DifferentOrder(i) => write!(f, "Square {} has a different order.", i),
+            Self::NotLatin(i) => write!(f, "Square {} is not a latin square.", i),
+            Self::NotOrthogonal(i, j) => write!(f, "Squares {} and {} are not orthogonal.", i, j),
+        }
+    }
+}
+
+// Checks that the squares are latin squares of the same order and that every two of them are orthogonal.
+pub fn verify_mols(squares: &[LatinSquare]) -> Result<(), MolsError> {
+    for (i, square) in squares.iter().enumerate() {
+        if square.0.len() != squares[0].0.len() {
+            return Err(MolsError::DifferentOrder(i));
+        }
+        if !PartialLatinSquare::from(square).is_valid() {
+            return Err(MolsError::NotLatin(i));
+        }
+    }
+
+    for i in 0..squares.len() {
+        for j in i + 1..squares.len() {
+            if !squares[i].is_orthogonal(&squares[j]) {
+                return Err(MolsError::NotOrthogonal(i, j));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// The q - 1 squares L_a[x][y] = ax + y over the field of order q, for a = 1, ..., q - 1, or None if q is not
+// a prime power. L_a and L_b are orthogonal for a != b, since ax + y = i and bx + y = j has exactly one solution,
+// so this is a complete set of MOLS.
+pub fn field_mols(q: usize) -> Option<Vec<LatinSquare>> {
+    let field = FiniteField::new(q)?;
+
+    Some(
+        (1..q)
+            .map(|a| {
+                LatinSquare(
+                    (0..q)
+                        .map(|x| (0..q).map(|y| field.add(field.mul(a, x), y)).collect())
+                        .collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+// The MacNeish product of two sets of MOLS: the direct products of the first squares, of the second squares and so
+// on. The products of two orthogonal pairs are orthogonal, so this is a set of min(a, b) MOLS.
+pub fn mols_product(a: &[LatinSquare], b: &[LatinSquare]) -> Vec<LatinSquare> {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| x.direct_product(y))
+        .collect()
+}
+
+// A set of MOLS of order n, from the finite fields of the prime powers in n joined by mols_product. There are
+// q - 1 squares for the smallest prime power q in n, which is all there can be when n is a prime power, and a
+// single square when n is 1 or twice an odd number.
+pub fn mols(n: usize) -> Vec<LatinSquare> {
+    finite_field::prime_power_factors(n)
+        .into_iter()
+        .map(|q| field_mols(q).unwrap())
+        .reduce(|a, b| mols_product(&a, &b))
+        .unwrap_or_else(|| vec![LatinSquare(vec![vec![0]])])
+}
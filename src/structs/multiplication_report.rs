@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+// The orders of the multiplication groups of a latin square, and its nuclei, commutant and center.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiplicationReport {
+    pub left_multiplication_group: usize,
+    pub right_multiplication_group: usize,
+    pub multiplication_group: usize,
+    // Only for loops.
+    pub inner_mapping_group: Option<usize>,
+    pub left_nucleus: Vec<usize>,
+    pub middle_nucleus: Vec<usize>,
+    pub right_nucleus: Vec<usize>,
+    pub nucleus: Vec<usize>,
+    pub commutant: Vec<usize>,
+    pub center: Vec<usize>,
+}
+
+fn set_text(elements: &[usize]) -> String {
+    let elements: Vec<String> = elements.iter().map(|x| x.to_string()).collect();
+
+    format!("{{{}}}", elements.join(", "))
+}
+
+impl Display for MultiplicationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "|LMlt|: {}, |RMlt|: {}, |Mlt|: {}",
+            self.left_multiplication_group,
+            self.right_multiplication_group,
+            self.multiplication_group
+        )?;
+
+        if let Some(order) = self.inner_mapping_group {
+            writeln!(f, "|Inn|: {}", order)?;
+        }
+
+        writeln!(
+            f,
+            "Nuclei: left {}, middle {}, right {}, all {}",
+            set_text(&self.left_nucleus),
+            set_text(&self.middle_nucleus),
+            set_text(&self.right_nucleus),
+            set_text(&self.nucleus)
+        )?;
+
+        write!(
+            f,
+            "Commutant: {}, Center: {}",
+            set_text(&self.commutant),
+            set_text(&self.center)
+        )
+    }
+}
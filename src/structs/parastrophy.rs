@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+pub const PARASTROPHIES: [Parastrophy; 6] = [
+    Parastrophy::Identity,
+    Parastrophy::Transpose,
+    Parastrophy::RowSymbolColumn,
+    Parastrophy::SymbolColumnRow,
+    Parastrophy::ColumnSymbolRow,
+    Parastrophy::SymbolRowColumn,
+];
+
+// The six conjugates of a latin square, given by permuting the roles of row, column and symbol in the
+// triples (row, column, symbol). The name gives what becomes the row, column and symbol of the conjugate,
+// e.g. SymbolColumnRow has the triple (symbol, column, row) for every triple (row, column, symbol).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Parastrophy {
+    Identity,
+    Transpose,
+    RowSymbolColumn,
+    SymbolColumnRow,
+    ColumnSymbolRow,
+    SymbolRowColumn,
+}
+
+impl Parastrophy {
+    // The positions in (row, column, symbol) that become the row, column and symbol of the conjugate.
+    pub fn order(&self) -> [usize; 3] {
+        match self {
+            Parastrophy::Identity => [0, 1, 2],
+            Parastrophy::Transpose => [1, 0, 2],
+            Parastrophy::RowSymbolColumn => [0, 2, 1],
+            Parastrophy::SymbolColumnRow => [2, 1, 0],
+            Parastrophy::ColumnSymbolRow => [1, 2, 0],
+            Parastrophy::SymbolRowColumn => [2, 0, 1],
+        }
+    }
+}
+
+impl Display for Parastrophy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Parastrophy::Identity => "(r, c, s)",
+            Parastrophy::Transpose => "(c, r, s)",
+            Parastrophy::RowSymbolColumn => "(r, s, c)",
+            Parastrophy::SymbolColumnRow => "(s, c, r)",
+            Parastrophy::ColumnSymbolRow => "(c, s, r)",
+            Parastrophy::SymbolRowColumn => "(s, r, c)",
+        };
+
+        write!(f, "{}", text)
+    }
+}
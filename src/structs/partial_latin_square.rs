@@ -0,0 +1,199 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::LatinSquare;
+use super::LatinSquareCover;
+
+// An n by n grid where some of the cells are filled. Represented as a vector of the rows, where None is an
+// empty cell. Always square, but the filled cells may break the latin square property, see is_valid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartialLatinSquare(pub Vec<Vec<Option<usize>>>);
+
+impl PartialLatinSquare {
+    // The n by n grid with every cell empty.
+    pub fn empty(n: usize) -> PartialLatinSquare {
+        PartialLatinSquare(vec![vec![None; n]; n])
+    }
+
+    pub fn order(&self) -> usize {
+        self.0.len()
+    }
+
+    // The number of filled cells.
+    pub fn filled(&self) -> usize {
+        self.0.iter().flatten().filter(|c| c.is_some()).count()
+    }
+
+    // Whether every filled symbol is less than n and appears at most once in its row and column.
+    pub fn is_valid(&self) -> bool {
+        let n = self.order();
+
+        let mut row_used: Vec<Vec<bool>> = vec![vec![false; n]; n];
+        let mut column_used: Vec<Vec<bool>> = vec![vec![false; n]; n];
+
+        for (r, row) in self.0.iter().enumerate() {
+            if row.len() != n {
+                return false;
+            }
+
+            for (c, cell) in row.iter().enumerate() {
+                if let Some(s) = cell {
+                    if *s >= n || row_used[r][*s] || column_used[c][*s] {
+                        return false;
+                    }
+
+                    row_used[r][*s] = true;
+                    column_used[c][*s] = true;
+                }
+            }
+        }
+
+        true
+    }
+
+    // The latin square, if every cell is filled and the square is valid.
+    pub fn to_latin_square(&self) -> Option<LatinSquare> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let rows: Option<Vec<Vec<usize>>> = self
+            .0
+            .iter()
+            .map(|row| row.iter().copied().collect())
+            .collect();
+
+        rows.map(LatinSquare)
+    }
+
+    // Calls found for every latin square that agrees with the filled cells, until found returns false.
+    // The completions are searched with LatinSquareCover, which always fills the cell, or places the symbol in
+    // the row or column, that has the fewest possibilities left, so forced cells are filled first and dead ends
+    // are found early.
+    pub fn for_each_completion(&self, found: &mut dyn FnMut(LatinSquare) -> bool) {
+        if self.is_valid() {
+            LatinSquareCover::from_partial(self).for_each_square(found);
+        }
+    }
+
+    // One completion, or None if the filled cells can not be completed to a latin square.
+    pub fn complete(&self) -> Option<LatinSquare> {
+        let mut result: Option<LatinSquare> = None;
+
+        self.for_each_completion(&mut |square| {
+            result = Some(square);
+            false
+        });
+
+        result
+    }
+
+    // Every completion, in no particular order.
+    pub fn completions(&self) -> Vec<LatinSquare> {
+        let mut result: Vec<LatinSquare> = vec![];
+
+        self.for_each_completion(&mut |square| {
+            result.push(square);
+            true
+        });
+
+        result
+    }
+
+    // The number of completions, without storing them.
+    pub fn count_completions(&self) -> usize {
+        let mut count: usize = 0;
+
+        self.for_each_completion(&mut |_| {
+            count += 1;
+            true
+        });
+
+        count
+    }
+}
+
+impl From<&LatinSquare> for PartialLatinSquare {
+    fn from(square: &LatinSquare) -> PartialLatinSquare {
+        PartialLatinSquare(
+            square
+                .0
+                .iter()
+                .map(|row| row.iter().map(|v| Some(*v)).collect())
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartialLatinSquareError {
+    InvalidCell(String),
+    // The row and its length, when it is not the same as the number of rows.
+    WrongRowLength(usize, usize),
+}
+
+impl Display for PartialLatinSquareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCell(c) => write!(f, "\"{}\" is neither a symbol nor \".\".", c),
+            Self::WrongRowLength(r, l) => write!(f, "Row {} has {} cells.", r, l),
+        }
+    }
+}
+
+// One row per line, with the cells separated by whitespace and "." for the empty cells. Blank lines are skipped.
+impl FromStr for PartialLatinSquare {
+    type Err = PartialLatinSquareError;
+
+    fn from_str(text: &str) -> Result<PartialLatinSquare, PartialLatinSquareError> {
+        let mut rows: Vec<Vec<Option<usize>>> = vec![];
+
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            let mut row: Vec<Option<usize>> = vec![];
+
+            for cell in line.split_whitespace() {
+                if cell == "." {
+                    row.push(None);
+                } else {
+                    let s = cell
+                        .parse::<usize>()
+                        .map_err(|_| PartialLatinSquareError::InvalidCell(cell.to_string()))?;
+                    row.push(Some(s));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        for (r, row) in rows.iter().enumerate() {
+            if row.len() != rows.len() {
+                return Err(PartialLatinSquareError::WrongRowLength(r, row.len()));
+            }
+        }
+
+        Ok(PartialLatinSquare(rows))
+    }
+}
+
+// The same format as FromStr.
+impl Display for PartialLatinSquare {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self
+            .0
+            .iter()
+            .map(|row| {
+                let cells: Vec<String> = row
+                    .iter()
+                    .map(|c| match c {
+                        Some(s) => s.to_string(),
+                        None => ".".to_string(),
+                    })
+                    .collect();
+
+                cells.join(" ")
+            })
+            .collect();
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 
 // Permutation on the form: x -> p[x] is a permutation.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Permutation(pub Vec<usize>);
 
 pub fn permutation_recursion(n: usize, part_of_permutation: Vec<usize>) -> Vec<Vec<usize>> {
@@ -26,15 +26,79 @@ pub fn generate_all_permutations(n: usize) -> Vec<Vec<usize>> {
     permutation_recursion(n, vec![])
 }
 
+// Iterates over all permutations on n elements in lexicographic order, the same order as generate_all.
+pub struct AllPermutations {
+    next: Option<Vec<usize>>,
+}
+
+impl Iterator for AllPermutations {
+    type Item = Permutation;
+
+    fn next(&mut self) -> Option<Permutation> {
+        let current = self.next.take()?;
+
+        // Find the next permutation in lexicographic order: take the last ascent p[i] < p[i + 1],
+        // swap p[i] with the last element larger than it, and reverse everything after i.
+        let mut next = current.clone();
+
+        if let Some(i) = (1..next.len()).rev().find(|&i| next[i - 1] < next[i]) {
+            let j = (i..next.len())
+                .rev()
+                .find(|&j| next[j] > next[i - 1])
+                .unwrap();
+
+            next.swap(i - 1, j);
+            next[i..].reverse();
+
+            self.next = Some(next);
+        }
+
+        Some(Permutation(current))
+    }
+}
+
 impl Permutation {
     // Generates all permutations.
     pub fn generate_all(n: usize) -> Vec<Permutation> {
-        let mut perms = generate_all_permutations(n);
-        let mut result: Vec<Permutation> = vec![];
-        for p in perms.drain(..) {
-            result.push(Permutation(p));
+        Permutation::iter_all(n).collect()
+    }
+
+    // Lazily iterates over all permutations, in the same order as generate_all.
+    pub fn iter_all(n: usize) -> AllPermutations {
+        AllPermutations {
+            next: Some((0..n).collect()),
         }
-        result
+    }
+
+    // The position of the permutation in generate_all, i.e. in lexicographic order, found from its Lehmer code.
+    pub fn rank(&self) -> usize {
+        let n = self.0.len();
+
+        let mut rank: usize = 0;
+
+        for i in 0..n {
+            // The Lehmer code: the number of later elements smaller than p[i].
+            let smaller = self.0[i + 1..].iter().filter(|&&x| x < self.0[i]).count();
+
+            rank = rank * (n - i) + smaller;
+        }
+
+        rank
+    }
+
+    // The permutation at position k in generate_all(n). The inverse of rank.
+    pub fn unrank(n: usize, mut k: usize) -> Permutation {
+        let mut remaining: Vec<usize> = (0..n).collect();
+        let mut result: Vec<usize> = vec![];
+
+        for i in 0..n {
+            let f = crate::common::factorial(n - 1 - i);
+
+            result.push(remaining.remove(k / f));
+            k %= f;
+        }
+
+        Permutation(result)
     }
 
     pub fn compose(&self, inner: &Permutation) -> Permutation {
@@ -47,13 +111,186 @@ impl Permutation {
         Permutation(result)
     }
 
+    // The permutation q with q(p(x)) = x.
+    pub fn inverse(&self) -> Permutation {
+        let mut result: Vec<usize> = vec![0; self.0.len()];
+
+        for (i, v) in self.0.iter().enumerate() {
+            result[*v] = i;
+        }
+
+        Permutation(result)
+    }
+
+    // The cycles of the permutation, fixed points included. Every cycle starts with its smallest element,
+    // and the cycles are sorted by their first element.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut visited: Vec<bool> = vec![false; self.0.len()];
+        let mut result: Vec<Vec<usize>> = vec![];
+
+        for start in 0..self.0.len() {
+            if visited[start] {
+                continue;
+            }
+
+            let mut cycle: Vec<usize> = vec![];
+            let mut x = start;
+
+            while !visited[x] {
+                visited[x] = true;
+                cycle.push(x);
+                x = self.0[x];
+            }
+
+            result.push(cycle);
+        }
+
+        result
+    }
+
+    // The lengths of the cycles, fixed points included, in decreasing order.
+    pub fn cycle_type(&self) -> Vec<usize> {
+        let mut lengths: Vec<usize> = self.cycles().iter().map(|c| c.len()).collect();
+
+        lengths.sort_by(|a, b| b.cmp(a));
+
+        lengths
+    }
+
+    // The smallest k > 0 such that p^k is the identity, i.e. the least common multiple of the cycle lengths.
+    pub fn order(&self) -> usize {
+        fn gcd(a: usize, b: usize) -> usize {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        }
+
+        self.cycles()
+            .iter()
+            .fold(1, |order, c| order / gcd(order, c.len()) * c.len())
+    }
+
+    // A permutation is even if it is a product of an even number of transpositions.
+    // A cycle of length l is a product of l - 1 transpositions.
+    pub fn is_even(&self) -> bool {
+        (self.0.len() - self.cycles().len()).is_multiple_of(2)
+    }
+
+    // 1 for even permutations and -1 for odd permutations.
+    pub fn sign(&self) -> i8 {
+        if self.is_even() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    // The permutation in cycle notation without fixed points, e.g. "(0 2)(1 3 4)". The identity is "()".
+    pub fn cycle_notation(&self) -> String {
+        let mut text: String = "".to_string();
+
+        for cycle in self.cycles().iter().filter(|c| c.len() > 1) {
+            text.push('(');
+            text.push_str(&cycle[0].to_string());
+
+            for x in cycle.iter().skip(1) {
+                text.push(' ');
+                text.push_str(&x.to_string());
+            }
+
+            text.push(')');
+        }
+
+        if text.is_empty() {
+            text.push_str("()");
+        }
+
+        text
+    }
+
+    // Parses a permutation on n elements from cycle notation, e.g. "(0 2)(1 3 4)". Elements can be
+    // separated by spaces or commas, and elements that are not mentioned are fixed points.
+    pub fn from_cycle_notation(n: usize, text: &str) -> Result<Permutation, CycleNotationError> {
+        let mut result: Vec<usize> = (0..n).collect();
+        let mut seen: Vec<bool> = vec![false; n];
+
+        let mut rest = text.trim();
+
+        while !rest.is_empty() {
+            let inner = rest
+                .strip_prefix('(')
+                .ok_or_else(|| CycleNotationError::ExpectedCycle(rest.to_string()))?;
+            let end = inner
+                .find(')')
+                .ok_or_else(|| CycleNotationError::UnclosedCycle(rest.to_string()))?;
+
+            let mut cycle: Vec<usize> = vec![];
+
+            for element in inner[..end]
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|e| !e.is_empty())
+            {
+                let x = element
+                    .parse::<usize>()
+                    .map_err(|_| CycleNotationError::InvalidElement(element.to_string()))?;
+
+                if x >= n {
+                    return Err(CycleNotationError::ElementTooLarge(x));
+                }
+                if seen[x] {
+                    return Err(CycleNotationError::RepeatedElement(x));
+                }
+
+                seen[x] = true;
+                cycle.push(x);
+            }
+
+            for (i, x) in cycle.iter().enumerate() {
+                result[*x] = cycle[(i + 1) % cycle.len()];
+            }
+
+            rest = inner[end + 1..].trim_start();
+        }
+
+        Ok(Permutation(result))
+    }
+
+    // Prints the permutation in two-line notation followed by cycle notation.
     pub fn print(&self) {
-        todo!()
+        println!("{}\n{:#}", self, self)
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum CycleNotationError {
+    ExpectedCycle(String),
+    UnclosedCycle(String),
+    InvalidElement(String),
+    ElementTooLarge(usize),
+    RepeatedElement(usize),
+}
+
+impl Display for CycleNotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ExpectedCycle(rest) => write!(f, "Expected \"(\" at \"{}\".", rest),
+            Self::UnclosedCycle(rest) => write!(f, "Missing \")\" in \"{}\".", rest),
+            Self::InvalidElement(e) => write!(f, "\"{}\" is not an element.", e),
+            Self::ElementTooLarge(x) => write!(f, "{} is too large for the permutation.", x),
+            Self::RepeatedElement(x) => write!(f, "{} appears more than once.", x),
+        }
+    }
+}
+
+// Two-line notation by default, and cycle notation with the alternate flag, i.e. format!("{:#}", p).
 impl Display for Permutation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return write!(f, "{}", self.cycle_notation());
+        }
+
         let mut text: String = "".to_string();
 
         text.push('0');
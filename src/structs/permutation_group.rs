@@ -0,0 +1,245 @@
+use rand::Rng;
+
+use super::Permutation;
+
+// A group of permutations on the points 0, ..., degree - 1, given by generators.
+// The group is stored as a stabilizer chain found with the Schreier-Sims algorithm, so the order, membership and
+// random elements can be found without listing the elements.
+#[derive(Debug, Clone)]
+pub struct PermutationGroup {
+    pub degree: usize,
+    pub generators: Vec<Permutation>,
+    levels: Vec<Level>,
+}
+
+// One level of the stabilizer chain. The generators fix the base points of the earlier levels, and
+// transversal[x] is an element taking the base point to x, for every x in the orbit of the base point.
+#[derive(Debug, Clone)]
+struct Level {
+    base_point: usize,
+    generators: Vec<Permutation>,
+    transversal: Vec<Option<Permutation>>,
+}
+
+impl Level {
+    fn new(degree: usize, base_point: usize) -> Level {
+        let mut transversal: Vec<Option<Permutation>> = vec![None; degree];
+        transversal[base_point] = Some(Permutation((0..degree).collect()));
+
+        Level {
+            base_point,
+            generators: vec![],
+            transversal,
+        }
+    }
+
+    fn orbit(&self) -> Vec<usize> {
+        (0..self.transversal.len())
+            .filter(|x| self.transversal[*x].is_some())
+            .collect()
+    }
+}
+
+fn is_identity(p: &Permutation) -> bool {
+    p.0.iter().enumerate().all(|(i, x)| i == *x)
+}
+
+impl PermutationGroup {
+    // The group generated by the permutations.
+    pub fn from_generators(degree: usize, generators: Vec<Permutation>) -> PermutationGroup {
+        PermutationGroup::with_base(degree, generators, &[])
+    }
+
+    // The group generated by the permutations, with a stabilizer chain starting with the given base points.
+    // More base points are added as needed.
+    fn with_base(degree: usize, generators: Vec<Permutation>, base: &[usize]) -> PermutationGroup {
+        let mut group = PermutationGroup {
+            degree,
+            generators,
+            levels: base.iter().map(|b| Level::new(degree, *b)).collect(),
+        };
+
+        for g in group.generators.clone() {
+            group.insert(0, g);
+        }
+
+        group
+    }
+
+    // Divides g by transversal elements, starting at the given level, until g no longer moves the base point
+    // of a level into its orbit. Returns that level, or the number of levels, together with what is left of g.
+    fn sift(&self, level: usize, mut g: Permutation) -> (usize, Permutation) {
+        for (i, l) in self.levels.iter().enumerate().skip(level) {
+            match &l.transversal[g.0[l.base_point]] {
+                Some(u) => g = u.inverse().compose(&g),
+                None => return (i, g),
+            }
+        }
+
+        (self.levels.len(), g)
+    }
+
+    // Makes sure g, which fixes the base points before the given level, is in the group of that level.
+    fn insert(&mut self, level: usize, g: Permutation) {
+        let (i, h) = self.sift(level, g);
+
+        if is_identity(&h) {
+            return;
+        }
+
+        if i == self.levels.len() {
+            let moved = (0..self.degree).find(|x| h.0[*x] != *x).unwrap();
+            self.levels.push(Level::new(self.degree, moved));
+        }
+
+        // The new generator fixes every base point before level i, so it belongs to all those levels.
+        for j in (0..=i).rev() {
+            self.add_generator(j, h.clone());
+        }
+    }
+
+    fn add_generator(&mut self, level: usize, s: Permutation) {
+        self.levels[level].generators.push(s.clone());
+
+        for x in self.levels[level].orbit() {
+            self.apply_generator(level, &s, x);
+        }
+    }
+
+    // Follows the generator s from the point x in the orbit. A new point is added to the orbit, and otherwise
+    // the Schreier generator, which fixes the base point, is inserted into the next level.
+    fn apply_generator(&mut self, level: usize, s: &Permutation, x: usize) {
+        let y = s.0[x];
+        let t = s.compose(self.levels[level].transversal[x].as_ref().unwrap());
+
+        match &self.levels[level].transversal[y] {
+            Some(u) => {
+                let schreier_generator = u.inverse().compose(&t);
+                self.insert(level + 1, schreier_generator);
+            }
+            None => {
+                self.levels[level].transversal[y] = Some(t);
+
+                for s in self.levels[level].generators.clone() {
+                    self.apply_generator(level, &s, y);
+                }
+            }
+        }
+    }
+
+    // The base points of the stabilizer chain. Only the identity fixes all of them.
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|l| l.base_point).collect()
+    }
+
+    // A strong generating set: for every k the elements fixing the first k base points generate the stabilizer.
+    pub fn strong_generators(&self) -> Vec<Permutation> {
+        match self.levels.first() {
+            Some(level) => level.generators.clone(),
+            None => vec![],
+        }
+    }
+
+    // The product of the orbit sizes of the stabilizer chain.
+    pub fn order(&self) -> usize {
+        self.levels.iter().map(|l| l.orbit().len()).product()
+    }
+
+    pub fn contains(&self, p: &Permutation) -> bool {
+        p.0.len() == self.degree && is_identity(&self.sift(0, p.clone()).1)
+    }
+
+    // Every element of the group, sorted by Permutation::rank. Every element is a unique product of one
+    // transversal element from each level.
+    pub fn elements(&self) -> Vec<Permutation> {
+        let mut elements: Vec<Permutation> = vec![Permutation((0..self.degree).collect())];
+
+        for level in self.levels.iter().rev() {
+            let mut next: Vec<Permutation> = vec![];
+
+            for u in level.transversal.iter().flatten() {
+                for g in elements.iter() {
+                    next.push(u.compose(g));
+                }
+            }
+
+            elements = next;
+        }
+
+        elements.sort_by_cached_key(|p| p.rank());
+
+        elements
+    }
+
+    // The points that the group can move x to.
+    pub fn orbit(&self, x: usize) -> Vec<usize> {
+        let mut found: Vec<bool> = vec![false; self.degree];
+        let mut orbit: Vec<usize> = vec![x];
+        found[x] = true;
+
+        let mut k = 0;
+
+        while k < orbit.len() {
+            for s in self.generators.iter() {
+                let y = s.0[orbit[k]];
+
+                if !found[y] {
+                    found[y] = true;
+                    orbit.push(y);
+                }
+            }
+
+            k += 1;
+        }
+
+        orbit.sort();
+
+        orbit
+    }
+
+    // The orbits of the group, each sorted and ordered by their smallest point.
+    pub fn orbits(&self) -> Vec<Vec<usize>> {
+        let mut result: Vec<Vec<usize>> = vec![];
+        let mut found: Vec<bool> = vec![false; self.degree];
+
+        for x in 0..self.degree {
+            if !found[x] {
+                let orbit = self.orbit(x);
+
+                for y in orbit.iter() {
+                    found[*y] = true;
+                }
+
+                result.push(orbit);
+            }
+        }
+
+        result
+    }
+
+    // The subgroup of elements fixing x, found from a stabilizer chain with x as the first base point.
+    pub fn stabilizer(&self, x: usize) -> PermutationGroup {
+        let group = PermutationGroup::with_base(self.degree, self.strong_generators(), &[x]);
+
+        let generators = match group.levels.get(1) {
+            Some(level) => level.generators.clone(),
+            None => vec![],
+        };
+
+        PermutationGroup::from_generators(self.degree, generators)
+    }
+
+    // A uniformly random element, as a product of a random transversal element from each level.
+    pub fn random_element<R: Rng + ?Sized>(&self, rng: &mut R) -> Permutation {
+        let mut result = Permutation((0..self.degree).collect());
+
+        for level in self.levels.iter() {
+            let orbit = level.orbit();
+            let x = orbit[rng.gen_range(0..orbit.len())];
+
+            result = result.compose(level.transversal[x].as_ref().unwrap());
+        }
+
+        result
+    }
+}
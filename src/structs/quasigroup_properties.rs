@@ -0,0 +1,90 @@
+// Algebraic properties of a quasigroup, i.e. of the operation x * y = square[x][y].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuasigroupProperties {
+    // x(yz) = (xy)z
+    pub associative: bool,
+    // xx = x
+    pub idempotent: bool,
+    // x(yx) = (xy)x
+    pub flexible: bool,
+    // (xy)(uv) = (xu)(yv), also called entropic.
+    pub medial: bool,
+    // x(yz) = (xy)(xz)
+    pub left_distributive: bool,
+    // (yz)x = (yx)(zx)
+    pub right_distributive: bool,
+    // xy = yx and x(xy) = y
+    pub totally_symmetric: bool,
+    // xx = yy
+    pub unipotent: bool,
+    // Only defined when the quasigroup has a two-sided identity.
+    pub loop_properties: Option<LoopProperties>,
+}
+
+// Properties that are only defined for loops.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopProperties {
+    // z(x(zy)) = ((zx)z)y
+    pub moufang: bool,
+    // x(y(xz)) = (x(yx))z
+    pub left_bol: bool,
+    // ((zx)y)x = z((xy)x)
+    pub right_bol: bool,
+    // x^λ(xy) = y, where x^λ x = e.
+    pub left_inverse_property: bool,
+    // (yx)x^ρ = y, where x x^ρ = e.
+    pub right_inverse_property: bool,
+}
+
+impl QuasigroupProperties {
+    // The names of the properties that hold, leaving out associativity and the ones that follow from it,
+    // since an associative quasigroup is already classified as a group.
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = vec![];
+
+        if !self.associative && self.flexible {
+            names.push("Flexible");
+        }
+
+        if self.idempotent {
+            names.push("Idempotent");
+        }
+        if self.unipotent {
+            names.push("Unipotent");
+        }
+        if self.medial {
+            names.push("Medial");
+        }
+        if self.left_distributive {
+            names.push("Left Distributive");
+        }
+        if self.right_distributive {
+            names.push("Right Distributive");
+        }
+        if self.totally_symmetric {
+            names.push("Totally Symmetric");
+        }
+
+        if let Some(l) = &self.loop_properties {
+            if !self.associative {
+                if l.moufang {
+                    names.push("Moufang");
+                }
+                if l.left_bol {
+                    names.push("Left Bol");
+                }
+                if l.right_bol {
+                    names.push("Right Bol");
+                }
+                if l.left_inverse_property {
+                    names.push("Left Inverse Property");
+                }
+                if l.right_inverse_property {
+                    names.push("Right Inverse Property");
+                }
+            }
+        }
+
+        names
+    }
+}
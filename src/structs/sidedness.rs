@@ -1,6 +1,6 @@
 pub const SIDES: [Sidedness; 2] = [Sidedness::Left, Sidedness::Right];
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Sidedness {
     Left,
     Right,
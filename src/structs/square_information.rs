@@ -3,6 +3,7 @@ use std::fmt::Display;
 use super::AllAffineAutomorphisms;
 use super::LatinSquare;
 use super::LatinType;
+use super::Parastrophy;
 use super::Permutation;
 
 use super::traits::SpreadsheetDisplay;
@@ -16,6 +17,18 @@ pub enum SquareInformation {
     AllAffineAutomorphisms(AllAffineAutomorphisms),
     FingerprintIndex(usize),
     AutomorphismAndAffineSums((usize, usize)),
+    Autotopisms(usize),
+    Autoparatopisms(usize),
+    Transversals(usize),
+    // The different pairs of autotopism and autoparatopism counts.
+    AutotopismCounts(Vec<(usize, usize)>),
+    ConjugateSymmetry(Vec<Parastrophy>),
+    ClassIndex(usize),
+    Count(usize),
+    // The different numbers of automorphisms.
+    AutomorphismCounts(Vec<usize>),
+    // The different numbers of transversals.
+    TransversalCounts(Vec<usize>),
     Text(String),
     None,
 }
@@ -38,7 +51,7 @@ impl Display for SquareInformation {
             Self::None => {
                 text = "".to_string();
             }
-            Self::Permutation(p) => text = p.to_string(),
+            Self::Permutation(p) => text = format!("{}\n{:#}", p, p),
             Self::PermutationIndex(i) => {
                 text = "p_".to_string();
                 text.push_str(&i.to_string());
@@ -51,6 +64,35 @@ impl Display for SquareInformation {
             Self::AutomorphismAndAffineSums((aut, aff)) => {
                 text = format!("Aut: {}\nAAut: {}", aut, aff);
             }
+            Self::Autotopisms(count) | Self::Autoparatopisms(count) | Self::Transversals(count) => {
+                text = count.to_string()
+            }
+            Self::AutotopismCounts(counts) => {
+                text = counts
+                    .iter()
+                    .map(|(atp, aptp)| format!("Atp: {}, Aptp: {}", atp, aptp))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+            }
+            Self::ConjugateSymmetry(parastrophies) => {
+                text = parastrophies
+                    .iter()
+                    .map(|p| p.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n");
+            }
+            Self::ClassIndex(index) => {
+                text = "C_".to_string();
+                text.push_str(&index.to_string());
+            }
+            Self::Count(count) => text = count.to_string(),
+            Self::AutomorphismCounts(counts) | Self::TransversalCounts(counts) => {
+                text = counts
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+            }
             Self::Text(t) => text = t.to_string(),
         }
 
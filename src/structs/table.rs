@@ -1,3 +1,9 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::Bits;
+use super::ClassSummary;
+use super::IsomorphismClass;
+use super::LatinSquare;
 use super::LatinSquareClassification;
 use super::Permutation;
 use super::SquareInformation;
@@ -19,11 +25,19 @@ pub fn create_complete_table(
             SquareInformation::None,
             SquareInformation::None,
             SquareInformation::None,
+            SquareInformation::Text("Autotopisms".to_string()),
+            SquareInformation::Text("Autoparatopisms".to_string()),
+            SquareInformation::Text("Conjugate Symmetry".to_string()),
+            SquareInformation::Text("Transversals".to_string()),
         ],
         vec![
             SquareInformation::None,
             SquareInformation::None,
             SquareInformation::None,
+            SquareInformation::None,
+            SquareInformation::None,
+            SquareInformation::None,
+            SquareInformation::None,
         ],
     ];
 
@@ -38,6 +52,12 @@ pub fn create_complete_table(
         table[i + 2].push(SquareInformation::LatinSquare(s.square.clone()));
         table[i + 2].push(SquareInformation::LatinSquareIndex(s.index));
         table[i + 2].push(SquareInformation::Class(s.class.clone()));
+        table[i + 2].push(SquareInformation::Autotopisms(s.autotopisms));
+        table[i + 2].push(SquareInformation::Autoparatopisms(s.autoparatopisms));
+        table[i + 2].push(SquareInformation::ConjugateSymmetry(
+            s.conjugate_symmetry.clone(),
+        ));
+        table[i + 2].push(SquareInformation::Transversals(s.transversals));
 
         for affine_automorphisms in s.all_permutations_all_affine_automorphisms.iter() {
             table[i + 2].push(SquareInformation::AllAffineAutomorphisms(
@@ -49,74 +69,245 @@ pub fn create_complete_table(
     Table { table }
 }
 
-pub fn create_summary_table(
-    mut rows: Vec<LatinSquareClassification>,
-    perms: &[Permutation],
-) -> Table<SquareInformation> {
-    rows.sort_by_cached_key(|x| x.fingerprint_no_structure());
+// The sums of the summary table for every fingerprint, collected one square or one isomorphism class at a time.
+// Sums collected separately, e.g. on different threads, are combined with merge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummarySums {
+    // The conjugacy class of every permutation, by rank, and the ranks in every conjugacy class, i.e. the
+    // permutations with the same cycle type.
+    conjugacy_class: Vec<usize>,
+    conjugacy_classes: Vec<Vec<usize>>,
+    rows: BTreeMap<Bits, SummaryRow>,
+}
+
+// The row of the fingerprint, with the invariants of the square added.
+fn summary_row<'a>(
+    rows: &'a mut BTreeMap<Bits, SummaryRow>,
+    fingerprint: Bits,
+    s: &LatinSquareClassification,
+) -> &'a mut SummaryRow {
+    let perms = fingerprint.bits.len();
+    let row = rows.entry(fingerprint).or_default();
+
+    if row.sums.is_empty() {
+        row.sums = vec![(0, 0); perms];
+    }
+
+    row.autotopisms.insert((s.autotopisms, s.autoparatopisms));
+    row.transversals.insert(s.transversals);
+
+    row
+}
 
-    let mut previous_fingerprint = rows.first().unwrap().fingerprint_no_structure();
+#[derive(Debug, Clone, PartialEq, Default)]
+struct SummaryRow {
+    // The number of squares with the permutation as an automorphism and as an affine automorphism.
+    sums: Vec<(usize, usize)>,
+    // The different numbers of autotopisms and autoparatopisms.
+    autotopisms: BTreeSet<(usize, usize)>,
+    // The different numbers of transversals.
+    transversals: BTreeSet<usize>,
+}
 
-    let mut split_indices: Vec<usize> = vec![];
+impl SummarySums {
+    // The permutations must be Permutation::generate_all(n), since they are looked up by Permutation::rank.
+    pub fn new(perms: &[Permutation]) -> SummarySums {
+        let mut cycle_types: BTreeMap<Vec<usize>, usize> = BTreeMap::new();
+        let mut conjugacy_class: Vec<usize> = vec![];
+        let mut conjugacy_classes: Vec<Vec<usize>> = vec![];
 
-    let mut split_point: usize = 0;
+        for (i, p) in perms.iter().enumerate() {
+            let next = cycle_types.len();
+            let c = *cycle_types.entry(p.cycle_type()).or_insert(next);
 
-    for (i, r) in rows.iter_mut().enumerate() {
-        let f = r.fingerprint_no_structure();
+            if c == conjugacy_classes.len() {
+                conjugacy_classes.push(vec![]);
+            }
+
+            conjugacy_class.push(c);
+            conjugacy_classes[c].push(i);
+        }
+
+        SummarySums {
+            conjugacy_class,
+            conjugacy_classes,
+            rows: BTreeMap::new(),
+        }
+    }
 
-        if f > previous_fingerprint {
-            split_indices.push(i - split_point);
-            previous_fingerprint = f;
-            split_point = i;
+    // Adds a single square.
+    pub fn add(&mut self, s: &LatinSquareClassification) {
+        let row = summary_row(&mut self.rows, s.fingerprint_no_structure(), s);
+
+        for (j, c) in s
+            .all_permutations_all_affine_automorphisms
+            .iter()
+            .enumerate()
+        {
+            if c.0 {
+                row.sums[j].0 += 1;
+            }
+            if !c.1.is_empty() {
+                row.sums[j].1 += 1;
+            }
         }
     }
 
-    split_indices.push(rows.len() - split_point);
+    // Adds every square of an isomorphism class, given only the classification of its representative r, which
+    // is the same as adding the classifications of classify_isomorphism_class. The square r.apply_permutation(σ)
+    // has the automorphisms and affine automorphisms of r conjugated by σ, and the squares are counted by running
+    // σ through all n! permutations, which gives every square once for every automorphism of r.
+    pub fn add_class(
+        &mut self,
+        class: &IsomorphismClass,
+        representative: &LatinSquareClassification,
+        perms: &[Permutation],
+    ) {
+        let flags = &representative.all_permutations_all_affine_automorphisms;
+
+        let automorphisms: Vec<usize> = (0..flags.len()).filter(|i| flags[*i].0).collect();
+        let affine_automorphisms: Vec<usize> = (0..flags.len())
+            .filter(|i| !flags[*i].1.is_empty())
+            .collect();
+
+        // Without automorphisms other than the identity, every square of the class has the same fingerprint,
+        // and σασ^-1 runs through the conjugacy class of α, |C(α)| = n! / |class| times for every permutation.
+        if automorphisms.len() == 1 {
+            let mut fingerprint = vec![false; perms.len()];
+            fingerprint[0] = true;
+
+            let row = summary_row(&mut self.rows, Bits { bits: fingerprint }, representative);
+
+            row.sums[0].0 += class.orbit_size;
+
+            for a in affine_automorphisms {
+                let conjugates = &self.conjugacy_classes[self.conjugacy_class[a]];
+
+                for p in conjugates {
+                    row.sums[*p].1 += perms.len() / conjugates.len();
+                }
+            }
 
-    let mut table: Vec<Vec<SquareInformation>> =
-        vec![vec![SquareInformation::None], vec![SquareInformation::None]];
-    let mut sum_information: Vec<Vec<(usize, usize)>> = vec![];
+            return;
+        }
 
-    for (i, p) in perms.iter().enumerate() {
-        table[0].push(SquareInformation::Permutation(p.clone()));
-        table[1].push(SquareInformation::PermutationIndex(i));
-    }
+        // The automorphisms of the squares, as ranks, together with how often σ gives them and how often every
+        // permutation is an affine automorphism then.
+        let mut counts: BTreeMap<Vec<usize>, (usize, BTreeMap<usize, usize>)> = BTreeMap::new();
+
+        for sigma in perms {
+            let sigma_inverse = sigma.inverse();
+            let conjugate = |i: usize| sigma.compose(&perms[i]).compose(&sigma_inverse).rank();
+
+            let mut conjugates: Vec<usize> = automorphisms.iter().map(|a| conjugate(*a)).collect();
+            conjugates.sort();
 
-    let mut second: &mut [LatinSquareClassification] = &mut rows;
+            let (count, affine) = counts.entry(conjugates).or_default();
+            *count += 1;
 
-    for (f, i) in split_indices.into_iter().enumerate() {
-        let (s_1, s_2) = second.split_at_mut(i);
+            for a in affine_automorphisms.iter() {
+                *affine.entry(conjugate(*a)).or_default() += 1;
+            }
+        }
 
-        second = s_2;
+        for (conjugates, (count, affine)) in counts {
+            let mut fingerprint = vec![false; perms.len()];
+            for a in conjugates.iter() {
+                fingerprint[*a] = true;
+            }
 
-        let mut sum: Vec<(usize, usize)> = vec![(0, 0); perms.len()];
+            let row = summary_row(&mut self.rows, Bits { bits: fingerprint }, representative);
 
-        for s in s_1 {
-            for (j, c) in s
-                .all_permutations_all_affine_automorphisms
-                .iter()
-                .enumerate()
-            {
-                if c.0 {
-                    sum[j].0 += 1;
-                }
-                if !c.1.is_empty() {
-                    sum[j].1 += 1;
-                }
+            for a in conjugates {
+                row.sums[a].0 += count / automorphisms.len();
+            }
+            for (a, c) in affine {
+                row.sums[a].1 += c / automorphisms.len();
             }
         }
+    }
 
-        table.push(vec![SquareInformation::FingerprintIndex(f)]);
+    // Adds the sums collected by another SummarySums with the same permutations.
+    pub fn merge(&mut self, other: SummarySums) {
+        for (fingerprint, other_row) in other.rows {
+            let row = self.rows.entry(fingerprint).or_default();
 
-        for aut_aff in &sum {
-            table[f + 2].push(SquareInformation::AutomorphismAndAffineSums(*aut_aff));
+            if row.sums.is_empty() {
+                row.sums = vec![(0, 0); other_row.sums.len()];
+            }
+
+            for (sum, other_sum) in row.sums.iter_mut().zip(other_row.sums) {
+                sum.0 += other_sum.0;
+                sum.1 += other_sum.1;
+            }
+
+            row.autotopisms.extend(other_row.autotopisms);
+            row.transversals.extend(other_row.transversals);
         }
+    }
+}
+
+// Takes the classifications one at a time, so they can be streamed instead of stored.
+pub fn create_summary_table(
+    rows: impl IntoIterator<Item = LatinSquareClassification>,
+    perms: &[Permutation],
+) -> Table<SquareInformation> {
+    let mut sums = SummarySums::new(perms);
+
+    for s in rows {
+        sums.add(&s);
+    }
+
+    create_summary_table_from_sums(sums, perms)
+}
+
+pub fn create_summary_table_from_sums(
+    sums: SummarySums,
+    perms: &[Permutation],
+) -> Table<SquareInformation> {
+    let mut table: Vec<Vec<SquareInformation>> = vec![
+        vec![
+            SquareInformation::None,
+            SquareInformation::Text("Autotopisms".to_string()),
+            SquareInformation::Text("Transversals".to_string()),
+        ],
+        vec![
+            SquareInformation::None,
+            SquareInformation::None,
+            SquareInformation::None,
+        ],
+    ];
 
-        sum_information.push(sum);
+    for (i, p) in perms.iter().enumerate() {
+        table[0].push(SquareInformation::Permutation(p.clone()));
+        table[1].push(SquareInformation::PermutationIndex(i));
+    }
+
+    let rows: Vec<SummaryRow> = sums.rows.into_values().collect();
+    let sum_information: Vec<&Vec<(usize, usize)>> = rows.iter().map(|r| &r.sums).collect();
+
+    for (f, row) in rows.iter().enumerate() {
+        table.push(vec![
+            SquareInformation::FingerprintIndex(f),
+            SquareInformation::AutotopismCounts(row.autotopisms.iter().copied().collect()),
+            SquareInformation::TransversalCounts(row.transversals.iter().copied().collect()),
+        ]);
+
+        for aut_aff in row.sums.iter() {
+            table[f + 2].push(SquareInformation::AutomorphismAndAffineSums(*aut_aff));
+        }
     }
 
-    table.push(vec![SquareInformation::Text("Sum W/o 0".to_string())]);
-    table.push(vec![SquareInformation::Text("Sum All".to_string())]);
+    table.push(vec![
+        SquareInformation::Text("Sum W/o 0".to_string()),
+        SquareInformation::None,
+        SquareInformation::None,
+    ]);
+    table.push(vec![
+        SquareInformation::Text("Sum All".to_string()),
+        SquareInformation::None,
+        SquareInformation::None,
+    ]);
 
     let mut sum: Vec<(usize, usize)> = vec![];
 
@@ -147,3 +338,66 @@ pub fn create_summary_table(
 
     Table { table }
 }
+
+// One row per isotopy class or main class, with the number of squares and the automorphism data of the class.
+pub fn create_class_table(classes: &[ClassSummary]) -> Table<SquareInformation> {
+    let mut table: Vec<Vec<SquareInformation>> = vec![[
+        "",
+        "Canonical Form",
+        "Squares",
+        "Isomorphism Classes",
+        "Isotopy Classes",
+        "Loops",
+        "Autotopisms",
+        "Autoparatopisms",
+        "Transversals",
+        "Automorphisms",
+    ]
+    .iter()
+    .map(|t| SquareInformation::Text(t.to_string()))
+    .collect()];
+
+    for (i, class) in classes.iter().enumerate() {
+        table.push(vec![
+            SquareInformation::ClassIndex(i),
+            SquareInformation::LatinSquare(class.canonical_form.clone()),
+            SquareInformation::Count(class.squares),
+            SquareInformation::Count(class.isomorphism_classes),
+            SquareInformation::Count(class.isotopy_classes),
+            SquareInformation::Count(class.loops),
+            SquareInformation::Autotopisms(class.autotopisms),
+            SquareInformation::Autoparatopisms(class.autoparatopisms),
+            SquareInformation::Transversals(class.transversals),
+            SquareInformation::AutomorphismCounts(class.automorphisms.iter().copied().collect()),
+        ]);
+    }
+
+    table.push(vec![
+        SquareInformation::Text("Sum".to_string()),
+        SquareInformation::None,
+        SquareInformation::Count(classes.iter().map(|c| c.squares).sum()),
+        SquareInformation::Count(classes.iter().map(|c| c.isomorphism_classes).sum()),
+        SquareInformation::Count(classes.iter().map(|c| c.isotopy_classes).sum()),
+        SquareInformation::Count(classes.iter().map(|c| c.loops).sum()),
+    ]);
+
+    Table { table }
+}
+
+// One row per square of a set of MOLS, with its transversal count.
+pub fn create_mols_table(squares: &[LatinSquare]) -> Table<SquareInformation> {
+    let mut table: Vec<Vec<SquareInformation>> = vec![["", "Latin Square", "Transversals"]
+        .iter()
+        .map(|t| SquareInformation::Text(t.to_string()))
+        .collect()];
+
+    for (i, square) in squares.iter().enumerate() {
+        table.push(vec![
+            SquareInformation::Text(format!("L_{}", i + 1)),
+            SquareInformation::LatinSquare(square.clone()),
+            SquareInformation::Transversals(square.count_transversals()),
+        ]);
+    }
+
+    Table { table }
+}
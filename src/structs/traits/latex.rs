@@ -14,16 +14,30 @@ pub trait LaTeX {
 
 impl LaTeX for Permutation {
     fn latex(&self) -> String {
-        let mut text: String = "\\( \\begin{smallmatrix}\n".to_string();
+        // Two-line notation above cycle notation.
+        let mut text: String =
+            "\\begin{tabular}{@{}c@{}}\n    \\( \\begin{smallmatrix}\n".to_string();
+
+        text.push('0');
+
+        for i in 1..self.0.len() {
+            text.push_str(" & ");
+            text.push_str(&i.to_string());
+        }
+
+        text.push_str("\\\\\n");
 
         text.push_str(&self.0[0].to_string());
 
-        for p in self.0.iter() {
+        for p in self.0.iter().skip(1) {
             text.push_str(" & ");
             text.push_str(&p.to_string());
         }
 
-        text.push_str("\n\\end{smallmatrix} \\)");
+        // Math mode ignores spaces, so the elements of a cycle are separated by "\ ".
+        text.push_str("\n\\end{smallmatrix} \\)\\\\\n    \\( ");
+        text.push_str(&self.cycle_notation().replace(' ', "\\ "));
+        text.push_str(" \\)\n\\end{tabular}");
 
         text
     }
@@ -145,34 +159,13 @@ impl LaTeX for LatinType {
     fn latex(&self) -> String {
         let mut text: String = "".to_string();
 
-        let mut additional_rows: Vec<&str> = vec![];
-
         text.push_str("\\begin{tabular}{@{}c@{}}\n    ");
 
         text.push_str(&self.structure.latex());
-        text.push_str("\\\\");
 
-        match self.structure {
-            LatinStructure::Loop => {
-                if self.commutative {
-                    additional_rows.push("Commutative");
-                }
-            }
-            LatinStructure::Quasigroup => {
-                if self.commutative {
-                    additional_rows.push("Commutative");
-                } else if self.left_identity {
-                    additional_rows.push("Left Identity");
-                } else if self.right_identity {
-                    additional_rows.push("Right Identity");
-                }
-            }
-            _ => (),
-        }
-
-        for row in additional_rows {
-            text.push_str("\\hline\n    ");
-            text.push_str(row);
+        for row in self.additional_rows() {
+            text.push_str("\\\\\\hline\n    ");
+            text.push_str(&row);
         }
         text.push_str("\\end{tabular}");
 
@@ -236,6 +229,44 @@ impl LaTeX for SquareInformation {
             Self::AutomorphismAndAffineSums((aut, aff)) => {
                 text = format!("Automorphisms: {}\\\\\nAffine Automorphisms: {}", aut, aff);
             }
+            Self::Autotopisms(count) | Self::Autoparatopisms(count) | Self::Transversals(count) => {
+                text = count.to_string()
+            }
+            Self::AutotopismCounts(counts) => {
+                text = "\\begin{tabular}{@{}c@{}}\n    ".to_string();
+                text.push_str(
+                    &counts
+                        .iter()
+                        .map(|(atp, aptp)| format!("Atp: {}, Aptp: {}", atp, aptp))
+                        .collect::<Vec<String>>()
+                        .join("\\\\\n    "),
+                );
+                text.push_str("\n\\end{tabular}");
+            }
+            Self::ConjugateSymmetry(parastrophies) => {
+                text = "\\begin{tabular}{@{}c@{}}\n    ".to_string();
+                text.push_str(
+                    &parastrophies
+                        .iter()
+                        .map(|p| format!("\\( {} \\)", p))
+                        .collect::<Vec<String>>()
+                        .join("\\\\\n    "),
+                );
+                text.push_str("\n\\end{tabular}");
+            }
+            Self::ClassIndex(index) => {
+                text = "\\( C_{".to_string();
+                text.push_str(&index.to_string());
+                text.push_str("} \\)");
+            }
+            Self::Count(count) => text = count.to_string(),
+            Self::AutomorphismCounts(counts) | Self::TransversalCounts(counts) => {
+                text = counts
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+            }
             Self::Text(t) => text = t.clone(),
         }
 
@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use super::AffineMap;
+use super::Bits;
+use super::LatinSquareClassification;
+use super::Permutation;
+use super::Sidedness;
+
+// Something in the classifications that does not agree with the squares, or with the invariants the tables
+// are assumed to satisfy. Squares are given by LatinSquareClassification::index and permutations by
+// Permutation::rank.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    // A recorded automorphism or permutation index is not a permutation of the right degree.
+    IndexOutOfRange {
+        square: usize,
+        permutation: usize,
+        index: usize,
+    },
+    // The permutation is marked as an automorphism when it is not one, or the other way around.
+    AutomorphismFlag {
+        square: usize,
+        permutation: usize,
+        recorded: bool,
+    },
+    // An affine automorphism is recorded with a permutation that is not an automorphism of the square.
+    NotAnAutomorphism {
+        square: usize,
+        permutation: usize,
+        automorphism: usize,
+    },
+    // The recorded affine map is a different permutation than the one it is recorded under.
+    WrongAffineMap {
+        square: usize,
+        permutation: usize,
+        map: AffineMap,
+        found: usize,
+    },
+    // Every automorphism gives one affine map for every element and side, so 2n in total.
+    MissingAffineMaps {
+        square: usize,
+        automorphism: usize,
+        found: usize,
+        expected: usize,
+    },
+    // The "Aut:" sum of a fingerprint is not the number of its squares that have the permutation as an
+    // automorphism.
+    AutomorphismCount {
+        fingerprint: usize,
+        permutation: usize,
+        recorded: usize,
+        found: usize,
+    },
+    // The squares of a fingerprint do not all have the same automorphisms, so the number that have the
+    // permutation as one is neither 0 nor the number of squares.
+    InconsistentAutomorphismCount {
+        fingerprint: usize,
+        permutation: usize,
+        count: usize,
+        expected: usize,
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::IndexOutOfRange {
+                square,
+                permutation,
+                index,
+            } => write!(
+                f,
+                "Square {}, permutation {}: the index {} is out of range.",
+                square, permutation, index
+            ),
+            ValidationError::AutomorphismFlag {
+                square,
+                permutation,
+                recorded,
+            } => write!(
+                f,
+                "Square {}, permutation {}: recorded as {}an automorphism.",
+                square,
+                permutation,
+                if *recorded { "" } else { "not " }
+            ),
+            ValidationError::NotAnAutomorphism {
+                square,
+                permutation,
+                automorphism,
+            } => write!(
+                f,
+                "Square {}, permutation {}: the affine map uses permutation {}, which is not an automorphism.",
+                square, permutation, automorphism
+            ),
+            ValidationError::WrongAffineMap {
+                square,
+                permutation,
+                map,
+                found,
+            } => write!(
+                f,
+                "Square {}, permutation {}: the affine map {} is permutation {}.",
+                square, permutation, map, found
+            ),
+            ValidationError::MissingAffineMaps {
+                square,
+                automorphism,
+                found,
+                expected,
+            } => write!(
+                f,
+                "Square {}, automorphism {}: {} affine maps are recorded instead of {}.",
+                square, automorphism, found, expected
+            ),
+            ValidationError::AutomorphismCount {
+                fingerprint,
+                permutation,
+                recorded,
+                found,
+            } => write!(
+                f,
+                "Fingerprint F_{}, permutation {}: Aut is {}, but {} squares have the automorphism.",
+                fingerprint, permutation, recorded, found
+            ),
+            ValidationError::InconsistentAutomorphismCount {
+                fingerprint,
+                permutation,
+                count,
+                expected,
+            } => write!(
+                f,
+                "Fingerprint F_{}, permutation {}: Aut is {} instead of {}.",
+                fingerprint, permutation, count, expected
+            ),
+        }
+    }
+}
+
+// Checks every recorded automorphism and affine map against its square.
+pub fn validate_classification(
+    classification: &LatinSquareClassification,
+    perms: &[Permutation],
+) -> Vec<ValidationError> {
+    let mut errors: Vec<ValidationError> = vec![];
+
+    let square = &classification.square;
+    let index = classification.index;
+    let n = square.0.len();
+
+    // The number of affine maps recorded for every automorphism.
+    let mut counts: Vec<usize> = vec![0; perms.len()];
+
+    for (k, (automorphism, affine_maps)) in classification
+        .all_permutations_all_affine_automorphisms
+        .iter()
+        .enumerate()
+    {
+        if k >= perms.len() {
+            errors.push(ValidationError::IndexOutOfRange {
+                square: index,
+                permutation: k,
+                index: k,
+            });
+            continue;
+        }
+
+        if *automorphism != square.is_automorphism(&perms[k]) {
+            errors.push(ValidationError::AutomorphismFlag {
+                square: index,
+                permutation: k,
+                recorded: *automorphism,
+            });
+        }
+
+        for (i, v, side) in affine_maps {
+            if *i >= perms.len() || *v >= n {
+                errors.push(ValidationError::IndexOutOfRange {
+                    square: index,
+                    permutation: k,
+                    index: if *i >= perms.len() { *i } else { *v },
+                });
+                continue;
+            }
+
+            counts[*i] += 1;
+
+            if !square.is_automorphism(&perms[*i]) {
+                errors.push(ValidationError::NotAnAutomorphism {
+                    square: index,
+                    permutation: k,
+                    automorphism: *i,
+                });
+            }
+
+            let map = AffineMap {
+                left: (side == &Sidedness::Left).then_some(*v),
+                automorphism: perms[*i].clone(),
+                right: (side == &Sidedness::Right).then_some(*v),
+            };
+
+            let found = map.permutation(square).rank();
+
+            if found != k {
+                errors.push(ValidationError::WrongAffineMap {
+                    square: index,
+                    permutation: k,
+                    map,
+                    found,
+                });
+            }
+        }
+    }
+
+    for (i, p) in perms.iter().enumerate() {
+        let expected = if square.is_automorphism(p) { 2 * n } else { 0 };
+
+        if counts[i] != expected {
+            errors.push(ValidationError::MissingAffineMaps {
+                square: index,
+                automorphism: i,
+                found: counts[i],
+                expected,
+            });
+        }
+    }
+
+    errors
+}
+
+// Checks the "Aut:" sums of the summary table against the squares. The squares are grouped by their recorded
+// fingerprints, like in the summary table, and for every permutation the recorded sum is compared to the number
+// of squares that have it as an automorphism, which must be 0 or all of them. The fingerprints are numbered like
+// the rows of the summary table.
+pub fn validate_automorphism_counts<'a>(
+    classifications: impl IntoIterator<Item = &'a LatinSquareClassification>,
+    perms: &[Permutation],
+) -> Vec<ValidationError> {
+    // The number of squares with every fingerprint, the recorded sums and the sums found from the squares.
+    let mut sums: BTreeMap<Bits, (usize, Vec<usize>, Vec<usize>)> = BTreeMap::new();
+
+    for s in classifications {
+        let (squares, recorded, found) = sums
+            .entry(s.fingerprint_no_structure())
+            .or_insert_with(|| (0, vec![0; perms.len()], vec![0; perms.len()]));
+
+        *squares += 1;
+
+        for (j, c) in s
+            .all_permutations_all_affine_automorphisms
+            .iter()
+            .enumerate()
+        {
+            if c.0 && j < recorded.len() {
+                recorded[j] += 1;
+            }
+        }
+
+        for (j, p) in perms.iter().enumerate() {
+            if s.square.is_automorphism(p) {
+                found[j] += 1;
+            }
+        }
+    }
+
+    let mut errors: Vec<ValidationError> = vec![];
+
+    for (f, (squares, recorded, found)) in sums.into_values().enumerate() {
+        for (j, (recorded, found)) in recorded.into_iter().zip(found).enumerate() {
+            if recorded != found {
+                errors.push(ValidationError::AutomorphismCount {
+                    fingerprint: f,
+                    permutation: j,
+                    recorded,
+                    found,
+                });
+            }
+
+            if found != 0 && found != squares {
+                errors.push(ValidationError::InconsistentAutomorphismCount {
+                    fingerprint: f,
+                    permutation: j,
+                    count: found,
+                    expected: squares,
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+// Runs every check on the classifications.
+pub fn validate_classifications(
+    classifications: &[LatinSquareClassification],
+    perms: &[Permutation],
+) -> Vec<ValidationError> {
+    let mut errors: Vec<ValidationError> = vec![];
+
+    for c in classifications {
+        errors.append(&mut validate_classification(c, perms));
+    }
+
+    errors.append(&mut validate_automorphism_counts(classifications, perms));
+
+    errors
+}
@@ -0,0 +1,58 @@
+use simula_matrix_framework_rust::structs::AffineMap;
+use simula_matrix_framework_rust::Permutation;
+
+mod common;
+use common::{cyclic_group, symmetric_group};
+
+#[test]
+fn test_affine_maps_of_groups() {
+    // x -> a + kx + b = (a + b) + kx.
+    let z5 = cyclic_group(5);
+    assert_eq!(z5.affine_maps().len(), 20);
+    assert_eq!(z5.affine_group().order(), 20);
+
+    // x -> a p(x) b with p inner gives x -> cxd, which is 36 / |Z(S3)| = 36 maps.
+    let s3 = symmetric_group();
+    assert_eq!(s3.affine_maps().len(), 36);
+    assert_eq!(s3.affine_group().order(), 36);
+}
+
+#[test]
+fn test_compose_and_inverse() {
+    for square in [cyclic_group(5), symmetric_group()] {
+        let maps = square.affine_maps();
+
+        for f in maps.iter().step_by(3) {
+            let inverse = f.inverse(&square).unwrap();
+            assert_eq!(
+                inverse.permutation(&square),
+                f.permutation(&square).inverse()
+            );
+
+            for g in maps.iter().step_by(5) {
+                let composition = f.compose(g, &square).unwrap();
+
+                assert_eq!(
+                    composition.permutation(&square),
+                    f.permutation(&square).compose(&g.permutation(&square))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_from_permutation() {
+    let z5 = cyclic_group(5);
+
+    // x -> 2x + 1.
+    let f = Permutation((0..5).map(|x| (2 * x + 1) % 5).collect());
+    let map = AffineMap::from_permutation(&z5, &f).unwrap();
+
+    assert_eq!(map.permutation(&z5), f);
+    assert_eq!(map.to_string(), "(1 2 4 3) + 1");
+
+    // x -> x + 1 except that 0 and 1 are swapped is not affine.
+    let g = Permutation(vec![2, 0, 3, 4, 1]);
+    assert!(AffineMap::from_permutation(&z5, &g).is_none());
+}
@@ -0,0 +1,54 @@
+use simula_matrix_framework_rust::{LatinSquare, Permutation};
+
+mod common;
+use common::{cyclic_group, elementary_abelian_group};
+
+#[test]
+fn test_automorphism_group_matches_apply_permutation() {
+    let perms = Permutation::generate_all(4);
+
+    for square in LatinSquare::generate_all(4) {
+        let group = square.automorphism_group();
+
+        let fixing: Vec<Permutation> = perms
+            .iter()
+            .filter(|p| {
+                let mut w = square.clone();
+                w.apply_permutation((*p).clone());
+                w == square
+            })
+            .cloned()
+            .collect();
+
+        assert_eq!(group.elements(), fixing);
+
+        for p in perms.iter() {
+            assert_eq!(group.contains(p), fixing.contains(p));
+        }
+    }
+}
+
+#[test]
+fn test_automorphism_group_of_cyclic_group() {
+    // The automorphisms of Z_5 are x -> kx for k = 1, 2, 3, 4, generated by x -> 2x.
+    let group = cyclic_group(5).automorphism_group();
+
+    assert_eq!(group.order(), 4);
+    assert!(group.contains(&Permutation(vec![0, 2, 4, 1, 3])));
+    assert_eq!(group.generators.len(), 1);
+
+    // The automorphisms of Z_2 x Z_2 x Z_2 form GL(3, 2) of order 168.
+    let elementary = elementary_abelian_group(8);
+
+    assert_eq!(elementary.automorphism_group().order(), 168);
+}
+
+#[test]
+fn test_automorphism_counts_match_isomorphism_classes() {
+    for class in LatinSquare::isomorphism_classes(5) {
+        assert_eq!(
+            class.representative.automorphism_group().order(),
+            class.automorphisms
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use simula_matrix_framework_rust::{LatinSquare, Permutation};
+
+mod common;
+use common::{cyclic_group, elementary_abelian_group};
+
+#[test]
+fn test_autotopisms_match_brute_force() {
+    let perms = Permutation::generate_all(4);
+
+    for square in LatinSquare::generate_all(4).iter().step_by(7) {
+        let mut expected: usize = 0;
+
+        for a in perms.iter() {
+            for b in perms.iter() {
+                for c in perms.iter() {
+                    if square.apply_isotopism(&(a.clone(), b.clone(), c.clone())) == *square {
+                        expected += 1;
+                    }
+                }
+            }
+        }
+
+        let autotopisms = square.autotopisms();
+
+        assert_eq!(autotopisms.len(), expected);
+        assert!(autotopisms
+            .iter()
+            .all(|t| square.apply_isotopism(t) == *square));
+    }
+}
+
+#[test]
+fn test_autotopisms_of_groups() {
+    // A group G has |G|^2 |Aut(G)| autotopisms, and all its conjugates are isotopic to it.
+    let z5 = cyclic_group(5);
+
+    assert_eq!(z5.autotopisms().len(), 100);
+    assert_eq!(z5.count_autoparatopisms(), 600);
+
+    let klein = elementary_abelian_group(4);
+
+    assert_eq!(klein.autotopisms().len(), 16 * 6);
+    assert_eq!(klein.count_autoparatopisms(), 6 * 16 * 6);
+}
+
+#[test]
+fn test_autotopisms_contain_automorphisms() {
+    for class in LatinSquare::isomorphism_classes(5) {
+        let square = &class.representative;
+        let autotopisms = square.autotopisms();
+
+        assert_eq!(autotopisms.len() % class.automorphisms, 0);
+        assert_eq!(square.count_autoparatopisms() % autotopisms.len(), 0);
+
+        for p in square.automorphism_group().elements() {
+            assert!(autotopisms.contains(&(p.clone(), p.clone(), p)));
+        }
+    }
+}
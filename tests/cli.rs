@@ -0,0 +1,111 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_simula-matrix-framework-rust"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+// The command fails with exit code 2 and names the argument.
+fn assert_rejected(args: &[&str], argument: &str) {
+    let output = run(args);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .starts_with(&format!("Unknown option \"{}\".", argument)));
+}
+
+#[test]
+fn test_extra_arguments_are_rejected() {
+    assert_rejected(&["complete", "a.txt", "b.txt"], "b.txt");
+    assert_rejected(&["experiment", "foo", "bar", "-n", "5"], "bar");
+    assert_rejected(&["count", "-n", "3", "extra"], "extra");
+    assert_rejected(&["ascii", "extra", "-n", "3"], "extra");
+    assert_rejected(&["count", "-n", "3", "--unknown"], "--unknown");
+}
+
+#[test]
+fn test_unused_options_are_rejected() {
+    assert_rejected(&["count", "-n", "3", "--boxes", "2x2"], "--boxes");
+    assert_rejected(&["count", "-n", "3", "--output", "x"], "--output");
+    assert_rejected(&["validate", "-n", "3", "--kind", "summary"], "--kind");
+    assert_rejected(&["complete", "a.txt", "-n", "3"], "-n");
+    assert_rejected(&["experiment", "--list", "--count"], "--count");
+}
+
+#[test]
+fn test_write_errors_are_reported() {
+    let path = std::env::temp_dir()
+        .join("simula_matrix_missing")
+        .join("table.tex");
+
+    let output = run(&[
+        "latex",
+        "--table",
+        "mols",
+        "-n",
+        "3",
+        "--output",
+        path.to_str().unwrap(),
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Invalid input:"));
+
+    let path = path.with_extension("ods");
+
+    let output = run(&[
+        "spreadsheet",
+        "--kind",
+        "mols",
+        "-n",
+        "3",
+        "--output",
+        path.to_str().unwrap(),
+    ]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Invalid input:"));
+}
+
+#[test]
+fn test_positional_arguments() {
+    let output = run(&["count", "-n", "3"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Latin squares L(3): 12"));
+
+    let output = run(&["experiment", "--list"]);
+    assert!(output.status.success());
+
+    // The file is read, so a missing file is not an unknown option.
+    let output = run(&["complete", "missing.txt"]);
+    assert!(!String::from_utf8_lossy(&output.stderr).starts_with("Unknown option"));
+}
+
+#[test]
+fn test_mols_export() {
+    let output = run(&["latex", "--table", "mols", "-n", "4"]);
+    assert!(output.status.success());
+
+    // A header and one row for each of the 3 squares, each with 8 transversals.
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(text.contains("& Latin Square & Transversals"));
+    assert_eq!(text.matches("L_").count(), 3);
+    assert_eq!(text.matches("\\end{smallmatrix} \\) & 8").count(), 3);
+
+    let path = std::env::temp_dir().join("simula_matrix_mols_4.ods");
+    let _ = std::fs::remove_file(&path);
+
+    let output = run(&[
+        "spreadsheet",
+        "--kind",
+        "mols",
+        "-n",
+        "4",
+        "--output",
+        path.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert!(std::fs::metadata(&path).unwrap().len() > 0);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,30 @@
+// Latin squares shared by the tests. Every test file only uses some of them.
+#![allow(dead_code)]
+
+use simula_matrix_framework_rust::{LatinSquare, Permutation};
+
+// The cyclic group Z_n, x o y = x + y mod n.
+pub fn cyclic_group(n: usize) -> LatinSquare {
+    LatinSquare(
+        (0..n)
+            .map(|i| (0..n).map(|j| (i + j) % n).collect())
+            .collect(),
+    )
+}
+
+// The symmetric group on 3 points, with the elements numbered by Permutation::rank.
+pub fn symmetric_group() -> LatinSquare {
+    let perms = Permutation::generate_all(3);
+
+    LatinSquare(
+        perms
+            .iter()
+            .map(|p| perms.iter().map(|q| p.compose(q).rank()).collect())
+            .collect(),
+    )
+}
+
+// The group Z_2 x ... x Z_2 of order n, a power of two, as bitwise xor. The Klein four-group for n = 4.
+pub fn elementary_abelian_group(n: usize) -> LatinSquare {
+    LatinSquare((0..n).map(|i| (0..n).map(|j| i ^ j).collect()).collect())
+}
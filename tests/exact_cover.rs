@@ -0,0 +1,97 @@
+use simula_matrix_framework_rust::structs::{ExactCover, LatinSquareCover};
+use simula_matrix_framework_rust::LatinSquare;
+
+mod common;
+use common::cyclic_group;
+
+#[test]
+fn test_exact_cover() {
+    // The example from Knuth's "Dancing Links", with the columns A, ..., G numbered 0, ..., 6.
+    let mut cover = ExactCover::new(7, 0);
+    for columns in [
+        vec![2, 4, 5],
+        vec![0, 3, 6],
+        vec![1, 2, 5],
+        vec![0, 3],
+        vec![1, 6],
+        vec![3, 4, 6],
+    ] {
+        cover.add_row(&columns);
+    }
+
+    assert_eq!(cover.solutions(), vec![vec![0, 3, 4]]);
+
+    // A secondary column may be left uncovered, but not covered twice.
+    let mut cover = ExactCover::new(2, 1);
+    cover.add_row(&[0, 2]);
+    cover.add_row(&[1, 2]);
+    cover.add_row(&[0]);
+    cover.add_row(&[1]);
+
+    assert_eq!(cover.solutions(), vec![vec![0, 3], vec![1, 2], vec![2, 3]]);
+    assert_eq!(cover.count_solutions(), 3);
+}
+
+#[test]
+fn test_latin_square_cover() {
+    for n in 1..=4 {
+        let all = LatinSquareCover::new(n);
+        assert_eq!(all.count(), LatinSquare::count_all(n).unwrap());
+
+        let mut reduced = LatinSquareCover::new(n);
+        reduced.fix_identity(0);
+        assert_eq!(reduced.squares(), LatinSquare::generate_reduced(n));
+
+        let mut symmetric = LatinSquareCover::new(n);
+        symmetric.symmetric();
+        let expected: Vec<LatinSquare> = LatinSquare::generate_all(n)
+            .into_iter()
+            .filter(|s| s.commutative())
+            .collect();
+        assert_eq!(symmetric.squares(), expected);
+    }
+
+    // Extra constraints: a diagonal latin square has every symbol once on the main diagonal.
+    let mut diagonal = LatinSquareCover::new(4);
+    for s in 0..4 {
+        diagonal.add_constraint((0..4).map(|i| (i, i, s)).collect(), true);
+    }
+    for square in diagonal.squares() {
+        let mut symbols: Vec<usize> = (0..4).map(|i| square.0[i][i]).collect();
+        symbols.sort();
+        assert_eq!(symbols, vec![0, 1, 2, 3]);
+    }
+    assert_eq!(diagonal.count(), 48);
+}
+
+#[test]
+fn test_transversals() {
+    // Cyclic groups of even order have no transversals.
+    assert_eq!(cyclic_group(3).transversals().len(), 3);
+    assert_eq!(cyclic_group(4).transversals().len(), 0);
+    assert_eq!(cyclic_group(5).transversals().len(), 15);
+
+    // The main diagonal of Z5 is a transversal, since 2i runs through every element.
+    assert!(cyclic_group(5)
+        .transversals()
+        .contains(&vec![0, 1, 2, 3, 4]));
+}
+
+#[test]
+fn test_latin_square_cover_large_order() {
+    // More symbols than there are bits in a u64.
+    let n = 70;
+    let mut cover = LatinSquareCover::new(n);
+
+    // Fix every cell but the last row to the cyclic group, and keep 0 out of the last cell of that row.
+    for r in 0..n - 1 {
+        for c in 0..n {
+            cover.fix(r, c, (r + c) % n);
+        }
+    }
+    cover.forbid(n - 1, n - 1, 0);
+
+    let square = cover.first().unwrap();
+    assert_eq!(square.0[n - 1][n - 1], n - 2);
+    assert_eq!(cover.count(), 1);
+}
@@ -0,0 +1,91 @@
+use simula_matrix_framework_rust::structs::latin_square;
+use simula_matrix_framework_rust::structs::{GerechteDesign, GerechteError, Regions};
+use simula_matrix_framework_rust::{LatinSquare, PartialLatinSquare, Permutation};
+
+// A Sudoku with the symbols 1, ..., 9 written as 0, ..., 8.
+fn sudoku_clues(rows: [&str; 9]) -> PartialLatinSquare {
+    PartialLatinSquare(
+        rows.iter()
+            .map(|row| {
+                row.chars()
+                    .map(|c| c.to_digit(10).map(|d| d as usize - 1))
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn test_regions() {
+    let boxes = Regions::sudoku(2, 3);
+    assert_eq!(boxes.order(), 6);
+    assert_eq!(boxes.0[1], vec![0, 0, 0, 1, 1, 1]);
+    assert_eq!(boxes.0[2], vec![2, 2, 2, 3, 3, 3]);
+    assert_eq!(Regions::new(boxes.0.clone()), Ok(boxes));
+
+    assert_eq!(
+        Regions::new(vec![vec![0, 0], vec![0, 1]]),
+        Err(GerechteError::WrongRegionSize(0, 3))
+    );
+    assert_eq!(
+        Regions::new(vec![vec![0, 2], vec![1, 1]]),
+        Err(GerechteError::InvalidRegion(2))
+    );
+}
+
+#[test]
+fn test_count_designs() {
+    assert_eq!(Regions::sudoku(2, 2).count_designs(), 288);
+
+    // With the rows as regions every latin square is a gerechte design.
+    let rows = Regions::new((0..4).map(|r| vec![r; 4]).collect()).unwrap();
+    assert_eq!(rows.count_designs(), 576);
+
+    let cyclic = LatinSquare(vec![
+        vec![0, 1, 2, 3],
+        vec![1, 2, 3, 0],
+        vec![2, 3, 0, 1],
+        vec![3, 0, 1, 2],
+    ]);
+    assert_eq!(
+        GerechteDesign::new(cyclic, Regions::sudoku(2, 2)),
+        Err(GerechteError::RepeatedSymbol(0, 1))
+    );
+}
+
+#[test]
+fn test_sudoku_completion() {
+    let clues = sudoku_clues([
+        "53..7....",
+        "6..195...",
+        ".98....6.",
+        "8...6...3",
+        "4..8.3..1",
+        "7...2...6",
+        ".6....28.",
+        "...419..5",
+        "....8..79",
+    ]);
+    let regions = Regions::sudoku(3, 3);
+
+    assert_eq!(regions.count_completions(&clues), 1);
+
+    let design = regions.complete(&clues).unwrap();
+    assert_eq!(design.square.0[0], vec![4, 2, 3, 5, 6, 7, 8, 0, 1]);
+    assert!(GerechteDesign::new(design.square.clone(), regions.clone()).is_ok());
+
+    // Two equal clues in a box.
+    let mut invalid = clues.clone();
+    invalid.0[1][1] = Some(8);
+    assert_eq!(regions.complete(&invalid), None);
+}
+
+#[test]
+fn test_classify_designs() {
+    let perms = Permutation::generate_all(4);
+    let designs = Regions::sudoku(2, 2).designs();
+    let classification =
+        latin_square::classify_latin_squares(designs.iter().map(|d| &d.square), &perms);
+
+    assert_eq!(classification.count(), 288);
+}
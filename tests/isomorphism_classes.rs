@@ -0,0 +1,175 @@
+use simula_matrix_framework_rust::common::factorial;
+use simula_matrix_framework_rust::structs::latin_square::{
+    classify_all_isomorphism_classes, classify_all_isomorphism_classes_parallel,
+    classify_all_latin_squares_parallel, summarize_fingerprints, summarize_fingerprints_parallel,
+};
+use simula_matrix_framework_rust::structs::SummarySums;
+use simula_matrix_framework_rust::{classify_all_latin_squares, LatinSquare, Permutation};
+
+#[test]
+fn test_isomorphism_class_counts() {
+    // The number of quasigroups up to isomorphism.
+    let counts: Vec<usize> = (1..=4)
+        .map(|n| LatinSquare::isomorphism_classes(n).len())
+        .collect();
+
+    assert_eq!(counts, vec![1, 1, 5, 35]);
+}
+
+#[test]
+fn test_orbit_sizes_add_up_to_all_squares() {
+    for n in 2..=4 {
+        let classes = LatinSquare::isomorphism_classes(n);
+
+        let total: usize = classes.iter().map(|c| c.orbit_size).sum();
+
+        assert_eq!(total, LatinSquare::generate_all(n).len());
+
+        for c in classes.iter() {
+            assert_eq!(c.orbit_size * c.automorphisms, factorial(n));
+        }
+    }
+}
+
+#[test]
+fn test_classify_isomorphism_classes_matches_all_squares() {
+    let n = 4;
+
+    let perms = Permutation::generate_all(n);
+
+    let expected = classify_all_latin_squares(&LatinSquare::generate_all(n), &perms);
+    let result = classify_all_isomorphism_classes(&LatinSquare::isomorphism_classes(n), &perms);
+
+    assert_eq!(expected.len(), result.len());
+
+    for (e, r) in expected.iter().zip(result.iter()) {
+        assert_eq!(e.index, r.index);
+        assert_eq!(e.square, r.square);
+        assert_eq!(e.class.to_string(), r.class.to_string());
+        assert_eq!(
+            e.all_permutations_all_affine_automorphisms,
+            r.all_permutations_all_affine_automorphisms
+        );
+    }
+}
+
+#[test]
+fn test_summary_from_representatives() {
+    for n in 1..=4 {
+        let perms = Permutation::generate_all(n);
+        let classes = LatinSquare::isomorphism_classes(n);
+
+        let mut expected = SummarySums::new(&perms);
+        for c in classify_all_isomorphism_classes(&classes, &perms) {
+            expected.add(&c);
+        }
+
+        assert_eq!(summarize_fingerprints(&classes, &perms), expected);
+
+        // Both kinds of classes are counted, the ones with the identity as their only automorphism separately.
+        if n == 4 {
+            assert!(classes.iter().any(|c| c.automorphisms == 1));
+            assert!(classes.iter().any(|c| c.automorphisms > 1));
+        }
+    }
+}
+
+#[test]
+fn test_parallel_summary_is_the_same() {
+    // More classes than fit in one chunk, so that the threads share them.
+    let n = 5;
+
+    let perms = Permutation::generate_all(n);
+    let classes = LatinSquare::isomorphism_classes(n);
+
+    let sums = summarize_fingerprints(&classes, &perms);
+
+    for threads in [2, 3] {
+        assert_eq!(
+            summarize_fingerprints_parallel(&classes, &perms, threads),
+            sums
+        );
+    }
+}
+
+#[test]
+fn test_parallel_classification_keeps_order() {
+    let n = 4;
+
+    let perms = Permutation::generate_all(n);
+    let squares = LatinSquare::generate_all(n);
+
+    let expected = classify_all_latin_squares(&squares, &perms);
+    let parallel = classify_all_latin_squares_parallel(&squares, &perms, 3);
+    let parallel_classes =
+        classify_all_isomorphism_classes_parallel(&LatinSquare::isomorphism_classes(n), &perms, 3);
+
+    for result in [parallel, parallel_classes] {
+        assert_eq!(expected.len(), result.len());
+
+        for (e, r) in expected.iter().zip(result.iter()) {
+            assert_eq!(e.index, r.index);
+            assert_eq!(e.square, r.square);
+            assert_eq!(
+                e.all_permutations_all_affine_automorphisms,
+                r.all_permutations_all_affine_automorphisms
+            );
+        }
+    }
+}
+
+#[test]
+fn test_canonical_form() {
+    let classes = LatinSquare::isomorphism_classes(4);
+
+    for square in LatinSquare::generate_all(4) {
+        let (canonical_form, p) = square.canonical_form();
+
+        let mut w = square.clone();
+        w.apply_permutation(p);
+        assert_eq!(w, canonical_form);
+
+        let class = classes
+            .iter()
+            .find(|c| c.representative.canonical_form().0 == canonical_form)
+            .unwrap();
+        assert!(class
+            .orbit(&Permutation::generate_all(4))
+            .iter()
+            .any(|(_, s)| *s == square));
+    }
+
+    // Different classes have different canonical forms.
+    let mut canonical_forms: Vec<LatinSquare> = LatinSquare::isomorphism_classes(5)
+        .iter()
+        .map(|c| c.representative.canonical_form().0)
+        .collect();
+    canonical_forms.sort();
+    canonical_forms.dedup();
+
+    assert_eq!(canonical_forms.len(), 1411);
+}
+
+#[test]
+fn test_is_isomorphic() {
+    let classes = LatinSquare::isomorphism_classes(4);
+    let perms = Permutation::generate_all(4);
+
+    for class in classes.iter().step_by(3) {
+        for (_, square) in class.orbit(&perms).iter().step_by(5) {
+            let p = class.representative.is_isomorphic(square).unwrap();
+
+            let mut w = class.representative.clone();
+            w.apply_permutation(p);
+            assert_eq!(w, *square);
+        }
+
+        for other in classes.iter() {
+            let isomorphic = class.representative.is_isomorphic(&other.representative);
+            assert_eq!(
+                isomorphic.is_some(),
+                other.representative == class.representative
+            );
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use rand::seq::SliceRandom;
+
+use simula_matrix_framework_rust::common::factorial;
+use simula_matrix_framework_rust::structs::latin_square;
+use simula_matrix_framework_rust::{LatinSquare, Permutation};
+
+fn random_permutation(n: usize, rng: &mut impl rand::Rng) -> Permutation {
+    let mut p: Vec<usize> = (0..n).collect();
+    p.shuffle(rng);
+
+    Permutation(p)
+}
+
+#[test]
+fn test_isotopy_canonical_form_is_smallest_isotope() {
+    let perms = Permutation::generate_all(4);
+
+    for square in LatinSquare::generate_all(4).iter().step_by(37) {
+        let mut smallest = square.clone();
+
+        for a in perms.iter() {
+            for b in perms.iter() {
+                for c in perms.iter() {
+                    smallest =
+                        smallest.min(square.apply_isotopism(&(a.clone(), b.clone(), c.clone())));
+                }
+            }
+        }
+
+        assert_eq!(square.isotopy_canonical_form(), smallest);
+    }
+}
+
+#[test]
+fn test_canonical_forms_are_invariant() {
+    let mut rng = rand::thread_rng();
+
+    for class in LatinSquare::isomorphism_classes(5).iter().step_by(10) {
+        let square = &class.representative;
+        let canonical_form = square.isotopy_canonical_form();
+        let main_class_form = square.main_class_canonical_form();
+
+        for _ in 0..5 {
+            let isotopism = (
+                random_permutation(5, &mut rng),
+                random_permutation(5, &mut rng),
+                random_permutation(5, &mut rng),
+            );
+            let isotope = square.apply_isotopism(&isotopism);
+
+            assert_eq!(isotope.isotopy_canonical_form(), canonical_form);
+            assert_eq!(
+                isotope
+                    .conjugate(simula_matrix_framework_rust::structs::Parastrophy::Transpose)
+                    .main_class_canonical_form(),
+                main_class_form
+            );
+        }
+    }
+}
+
+#[test]
+fn test_class_counts() {
+    // The number of isotopy classes and main classes of order 1, ..., 6.
+    let isotopy_classes = [1, 1, 1, 2, 2, 22];
+    let main_classes = [1, 1, 1, 2, 2, 12];
+
+    for n in 1..=6 {
+        let isotopy =
+            latin_square::partition_into_isotopy_classes(LatinSquare::generate_reduced(n));
+        assert_eq!(isotopy.len(), isotopy_classes[n - 1]);
+
+        // Every main class is a union of isotopy classes.
+        let canonical_forms: Vec<LatinSquare> = isotopy.into_iter().map(|(c, _)| c).collect();
+        assert_eq!(
+            latin_square::partition_into_main_classes(canonical_forms).len(),
+            main_classes[n - 1]
+        );
+    }
+}
+
+#[test]
+fn test_class_summaries() {
+    let classes = LatinSquare::isomorphism_classes(5);
+    let isotopy_classes = latin_square::summarize_isotopy_classes(&classes, 1);
+    let main_classes = latin_square::summarize_main_classes(&classes, 1);
+
+    assert_eq!(isotopy_classes.len(), 2);
+    assert_eq!(main_classes.len(), 2);
+
+    for summaries in [&isotopy_classes, &main_classes] {
+        assert_eq!(summaries.iter().map(|c| c.squares).sum::<usize>(), 161280);
+        assert_eq!(
+            summaries
+                .iter()
+                .map(|c| c.isomorphism_classes)
+                .sum::<usize>(),
+            classes.len()
+        );
+        // There are 6 loops of order 5 up to isomorphism, one of them the cyclic group.
+        assert_eq!(summaries.iter().map(|c| c.loops).sum::<usize>(), 6);
+    }
+
+    // The squares of an isotopy class are the orbit of the canonical form under all (5!)^3 isotopisms.
+    for class in isotopy_classes {
+        assert_eq!(class.squares * class.autotopisms, factorial(5).pow(3));
+    }
+}
@@ -0,0 +1,180 @@
+use simula_matrix_framework_rust::structs::latin_square::classify_latin_squares;
+use simula_matrix_framework_rust::structs::LatinStructure;
+use simula_matrix_framework_rust::{classify_all_latin_squares, LatinSquare, Permutation};
+
+#[test]
+fn test_generate_all_counts() {
+    let counts: Vec<usize> = (2..=4)
+        .map(|n| LatinSquare::generate_all(n).len())
+        .collect();
+
+    assert_eq!(counts, vec![2, 12, 576]);
+}
+
+#[test]
+fn test_classify_all_latin_squares_identity_is_automorphism() {
+    let squares = LatinSquare::generate_all(3);
+    let perms = Permutation::generate_all(3);
+
+    let classification = classify_all_latin_squares(&squares, &perms);
+
+    assert_eq!(classification.len(), squares.len());
+
+    for c in &classification {
+        assert!(c.all_permutations_all_affine_automorphisms[0].0);
+    }
+}
+
+#[test]
+fn test_cyclic_group_is_abelian() {
+    let square = LatinSquare(vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]]);
+
+    assert_eq!(square.classify(), LatinStructure::Abelian);
+}
+
+#[test]
+fn test_associative_squares_of_order_4_are_groups() {
+    let squares = LatinSquare::generate_all(4);
+
+    let associative = squares.iter().filter(|s| s.associative()).count();
+    let groups = squares
+        .iter()
+        .filter(|s| {
+            matches!(
+                s.classify(),
+                LatinStructure::Group | LatinStructure::Abelian
+            )
+        })
+        .count();
+
+    // 12 labelled copies of Z_4 and 4 of the Klein four-group.
+    assert_eq!(associative, 16);
+    assert_eq!(groups, 16);
+}
+
+#[test]
+fn test_steiner_quasigroup_properties() {
+    // x * y = -x - y mod 3
+    let square = LatinSquare(vec![vec![0, 2, 1], vec![2, 1, 0], vec![1, 0, 2]]);
+
+    let properties = square.properties();
+
+    assert!(!properties.associative);
+    assert!(properties.idempotent);
+    assert!(properties.flexible);
+    assert!(properties.medial);
+    assert!(properties.left_distributive);
+    assert!(properties.right_distributive);
+    assert!(properties.totally_symmetric);
+    assert!(!properties.unipotent);
+    assert!(properties.loop_properties.is_none());
+}
+
+#[test]
+fn test_non_associative_loop_properties() {
+    let square = LatinSquare(vec![
+        vec![0, 1, 2, 3, 4],
+        vec![1, 0, 3, 4, 2],
+        vec![2, 4, 0, 1, 3],
+        vec![3, 2, 4, 0, 1],
+        vec![4, 3, 1, 2, 0],
+    ]);
+
+    assert_eq!(square.classify(), LatinStructure::Loop);
+
+    let properties = square.properties();
+    let loop_properties = properties.loop_properties.unwrap();
+
+    assert!(!properties.associative);
+    assert!(!loop_properties.moufang);
+}
+
+#[test]
+fn test_one_sided_identities() {
+    // Row 1 is 0, 1, 2, so 1 is a left identity, but no column reads 0, 1, 2.
+    let left = LatinSquare(vec![vec![1, 2, 0], vec![0, 1, 2], vec![2, 0, 1]]);
+    let right = LatinSquare(vec![vec![1, 0, 2], vec![2, 1, 0], vec![0, 2, 1]]);
+
+    let left_type = left.classify_structure();
+    assert_eq!(left_type.structure, LatinStructure::Quasigroup);
+    assert_eq!(left_type.left_identity, Some(1));
+    assert_eq!(left_type.right_identity, None);
+    assert_eq!(left_type.identity, None);
+
+    let right_type = right.classify_structure();
+    assert_eq!(right_type.left_identity, None);
+    assert_eq!(right_type.right_identity, Some(1));
+    assert_eq!(right_type.identity, None);
+}
+
+#[test]
+fn test_commutative_quasigroup_reports_identity() {
+    // Z_3 with the identity relabelled to 2.
+    let square = LatinSquare(vec![vec![1, 2, 0], vec![2, 0, 1], vec![0, 1, 2]]);
+
+    let latin_type = square.classify_structure();
+
+    assert!(latin_type.commutative);
+    assert_eq!(latin_type.identity, Some(2));
+    assert_eq!(latin_type.to_string(), "Abelian\nIdentity: 2\nMedial");
+}
+
+#[test]
+fn test_reduced_latin_square_counts() {
+    let counts: Vec<usize> = (1..=5).map(LatinSquare::count_reduced).collect();
+
+    assert_eq!(counts, vec![1, 1, 1, 4, 56]);
+    assert_eq!(LatinSquare::generate_reduced(4).len(), 4);
+    assert_eq!(LatinSquare::count_all(5), Some(161280));
+
+    // The empty square is the only one of order 0.
+    assert_eq!(LatinSquare::count_reduced(0), 1);
+    assert_eq!(LatinSquare::count_all(0), Some(1));
+
+    // L(8) = 8! 7! R(8) is larger than a u64.
+    assert_eq!(
+        LatinSquare::count_all_from_reduced(7, 16942080),
+        Some(61479419904000)
+    );
+    assert_eq!(LatinSquare::count_all_from_reduced(8, 535281401856), None);
+}
+
+#[test]
+fn test_expand_reduced_gives_all_latin_squares() {
+    for n in 2..=4 {
+        assert_eq!(
+            LatinSquare::generate_all_from_reduced(n),
+            LatinSquare::generate_all(n)
+        );
+    }
+}
+
+#[test]
+fn test_iter_all_matches_generate_all() {
+    assert_eq!(LatinSquare::iter_all(1).count(), 1);
+
+    for n in 2..=4 {
+        let iterated: Vec<LatinSquare> = LatinSquare::iter_all(n).collect();
+
+        assert_eq!(iterated, LatinSquare::generate_all(n));
+    }
+}
+
+#[test]
+fn test_streamed_classification_matches_classify_all() {
+    let perms = Permutation::generate_all(3);
+
+    let streamed: Vec<_> = classify_latin_squares(LatinSquare::iter_all(3), &perms).collect();
+    let stored = classify_all_latin_squares(&LatinSquare::generate_all(3), &perms);
+
+    assert_eq!(streamed.len(), stored.len());
+
+    for (a, b) in streamed.iter().zip(stored.iter()) {
+        assert_eq!(a.index, b.index);
+        assert_eq!(a.square, b.square);
+        assert_eq!(
+            a.all_permutations_all_affine_automorphisms,
+            b.all_permutations_all_affine_automorphisms
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use simula_matrix_framework_rust::structs::latin_square;
+use simula_matrix_framework_rust::LatinSquare;
+
+#[test]
+fn test_divisions() {
+    let square = LatinSquare(vec![
+        vec![1, 2, 0, 3],
+        vec![3, 0, 2, 1],
+        vec![0, 3, 1, 2],
+        vec![2, 1, 3, 0],
+    ]);
+
+    for x in 0..4 {
+        for y in 0..4 {
+            assert_eq!(square.0[x][square.left_division(x, y)], y);
+            assert_eq!(square.0[square.right_division(x, y)][y], x);
+        }
+    }
+}
+
+#[test]
+fn test_principal_loop_isotopes() {
+    for class in LatinSquare::isomorphism_classes(4) {
+        let square = &class.representative;
+
+        for a in 0..4 {
+            for b in 0..4 {
+                let isotope = square.principal_loop_isotope(a, b);
+
+                assert_eq!(isotope.identity(), Some(square.0[a][b]));
+                assert_eq!(
+                    isotope.isotopy_canonical_form(),
+                    square.isotopy_canonical_form()
+                );
+            }
+        }
+    }
+
+    // A loop is its own principal isotope at its identity.
+    let z3 = LatinSquare(vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]]);
+    assert_eq!(z3.principal_loop_isotope(0, 0), z3);
+}
+
+#[test]
+fn test_principal_loop_isotope_classes() {
+    let classes = LatinSquare::isomorphism_classes(5);
+    let loops = latin_square::principal_loop_isotope_classes(&classes);
+
+    for (class, loop_classes) in classes.iter().zip(loops.iter()) {
+        assert!(!loop_classes.is_empty());
+
+        for l in loop_classes {
+            assert!(classes[*l].representative.identity().is_some());
+        }
+
+        // A loop is one of its own principal isotopes.
+        if let Some(e) = class.representative.identity() {
+            let index = classes
+                .iter()
+                .position(|c| c.representative == class.representative)
+                .unwrap();
+
+            assert_eq!(
+                class.representative.principal_loop_isotope(e, e),
+                class.representative
+            );
+            assert!(loop_classes.contains(&index));
+        }
+    }
+
+    // The cyclic group is only isotopic to itself, and the other isotopy class has the 5 other loops.
+    let mut sizes: Vec<usize> = loops.iter().map(|l| l.len()).collect();
+    sizes.sort();
+    sizes.dedup();
+    assert_eq!(sizes, vec![1, 5]);
+}
@@ -0,0 +1,140 @@
+use simula_matrix_framework_rust::structs::finite_field;
+use simula_matrix_framework_rust::structs::mols;
+use simula_matrix_framework_rust::structs::table;
+use simula_matrix_framework_rust::structs::{FiniteField, MolsError};
+use simula_matrix_framework_rust::{LatinSquare, SquareInformation};
+
+mod common;
+use common::{cyclic_group, elementary_abelian_group};
+
+#[test]
+fn test_prime_powers() {
+    assert_eq!(finite_field::prime_power(8), Some((2, 3)));
+    assert_eq!(finite_field::prime_power(9), Some((3, 2)));
+    assert_eq!(finite_field::prime_power(7), Some((7, 1)));
+    assert_eq!(finite_field::prime_power(6), None);
+    assert_eq!(finite_field::prime_power(1), None);
+
+    assert_eq!(finite_field::prime_power_factors(360), vec![8, 9, 5]);
+    assert!(finite_field::prime_power_factors(1).is_empty());
+}
+
+#[test]
+fn test_field_axioms() {
+    for q in [2, 3, 4, 5, 7, 8, 9, 16, 25, 27] {
+        let field = FiniteField::new(q).unwrap();
+        assert_eq!(field.order(), q);
+
+        for x in 0..q {
+            assert_eq!(field.add(x, 0), x);
+            assert_eq!(field.mul(x, 1), x);
+
+            // Every non-zero element has an inverse.
+            if x != 0 {
+                assert!((1..q).any(|y| field.mul(x, y) == 1));
+            }
+
+            for y in 0..q {
+                assert_eq!(field.add(x, y), field.add(y, x));
+                assert_eq!(field.mul(x, y), field.mul(y, x));
+
+                for z in 0..q {
+                    assert_eq!(
+                        field.mul(x, field.add(y, z)),
+                        field.add(field.mul(x, y), field.mul(x, z))
+                    );
+                    assert_eq!(field.mul(x, field.mul(y, z)), field.mul(field.mul(x, y), z));
+                }
+            }
+        }
+    }
+
+    assert!(FiniteField::new(6).is_none());
+    assert!(FiniteField::new(12).is_none());
+}
+
+#[test]
+fn test_complete_sets_for_prime_powers() {
+    for q in [2, 3, 4, 5, 7, 8, 9] {
+        let squares = mols::field_mols(q).unwrap();
+
+        assert_eq!(squares.len(), q - 1);
+        assert_eq!(mols::verify_mols(&squares), Ok(()));
+    }
+
+    assert!(mols::field_mols(10).is_none());
+}
+
+#[test]
+fn test_macneish_sets() {
+    // The smallest prime power in n, minus one.
+    for (n, count) in [(1, 1), (6, 1), (10, 1), (12, 2), (15, 2), (20, 3), (36, 3)] {
+        let squares = mols::mols(n);
+
+        assert_eq!(squares.len(), count);
+        assert!(squares.iter().all(|s| s.0.len() == n));
+        assert_eq!(mols::verify_mols(&squares), Ok(()));
+    }
+
+    assert_eq!(mols::mols(9).len(), 8);
+}
+
+#[test]
+fn test_direct_product() {
+    let product = cyclic_group(2).direct_product(&cyclic_group(3));
+
+    // Z2 x Z3 is Z6, with 1 as the element (0, 1).
+    assert_eq!(product.0.len(), 6);
+    assert_eq!(mols::verify_mols(&[product]), Ok(()));
+
+    // The Klein four-group is Z2 x Z2.
+    assert_eq!(
+        cyclic_group(2).direct_product(&cyclic_group(2)),
+        elementary_abelian_group(4)
+    );
+}
+
+#[test]
+fn test_rejected_sets() {
+    let squares = mols::field_mols(5).unwrap();
+
+    assert_eq!(
+        mols::verify_mols(&[squares[0].clone(), squares[1].clone(), squares[0].clone()]),
+        Err(MolsError::NotOrthogonal(0, 2))
+    );
+    assert_eq!(
+        mols::verify_mols(&[squares[0].clone(), cyclic_group(4)]),
+        Err(MolsError::DifferentOrder(1))
+    );
+    assert_eq!(
+        mols::verify_mols(&[cyclic_group(3), LatinSquare(vec![vec![0, 1, 2]; 3])]),
+        Err(MolsError::NotLatin(1))
+    );
+
+    // Z4 has no orthogonal mate.
+    assert!(!cyclic_group(4).is_orthogonal(&mols::field_mols(4).unwrap()[0]));
+    assert_eq!(mols::verify_mols(&[]), Ok(()));
+}
+
+#[test]
+fn test_mols_table() {
+    let squares = mols::mols(4);
+    let table = table::create_mols_table(&squares).table;
+
+    // A header and one row per square.
+    assert_eq!(table.len(), 4);
+    assert!(table.iter().all(|row| row.len() == 3));
+    assert!(matches!(&table[0][1], SquareInformation::Text(t) if t == "Latin Square"));
+    assert!(matches!(&table[0][2], SquareInformation::Text(t) if t == "Transversals"));
+
+    for (i, square) in squares.iter().enumerate() {
+        let row = &table[i + 1];
+        let name = format!("L_{}", i + 1);
+
+        assert!(matches!(&row[0], SquareInformation::Text(t) if *t == name));
+        assert!(matches!(&row[1], SquareInformation::LatinSquare(s) if s == square));
+
+        // Every square over the field of order 4 is isotopic to the Klein four-group, which has 8 transversals.
+        assert!(matches!(row[2], SquareInformation::Transversals(8)));
+    }
+}
@@ -0,0 +1,45 @@
+use simula_matrix_framework_rust::LatinSquare;
+
+mod common;
+use common::{cyclic_group, symmetric_group};
+
+#[test]
+fn test_multiplication_groups_of_groups() {
+    // The translations of an abelian group are the group itself, and every inner mapping is trivial.
+    let z5 = cyclic_group(5);
+    assert_eq!(z5.left_multiplication_group().order(), 5);
+    assert_eq!(z5.multiplication_group().order(), 5);
+    assert_eq!(z5.inner_mapping_group().unwrap().order(), 1);
+
+    // For a group G the multiplication group is (G x G) / Z(G) and the inner mappings are the inner automorphisms.
+    let s3 = symmetric_group();
+    assert_eq!(s3.left_multiplication_group().order(), 6);
+    assert_eq!(s3.right_multiplication_group().order(), 6);
+    assert_eq!(s3.multiplication_group().order(), 36);
+    assert_eq!(s3.inner_mapping_group().unwrap().order(), 6);
+}
+
+#[test]
+fn test_nuclei() {
+    let s3 = symmetric_group();
+    assert_eq!(s3.nucleus(), (0..6).collect::<Vec<usize>>());
+    assert_eq!(s3.commutant(), vec![0]);
+    assert_eq!(s3.center(), vec![0]);
+
+    // No identity, and only 0 and 1 associate from the left.
+    let quasigroup = LatinSquare(vec![
+        vec![0, 1, 2, 3],
+        vec![1, 0, 3, 2],
+        vec![3, 2, 0, 1],
+        vec![2, 3, 1, 0],
+    ]);
+    assert_eq!(quasigroup.inner_mapping_group().map(|g| g.order()), None);
+    assert_eq!(quasigroup.left_nucleus(), vec![0, 1]);
+    assert!(quasigroup.middle_nucleus().is_empty());
+    assert!(quasigroup.right_nucleus().is_empty());
+    assert!(quasigroup.center().is_empty());
+
+    let report = quasigroup.multiplication_report();
+    assert_eq!(report.left_multiplication_group, 4);
+    assert_eq!(report.multiplication_group, 8);
+}
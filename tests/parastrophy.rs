@@ -0,0 +1,65 @@
+use simula_matrix_framework_rust::structs::{Parastrophy, PARASTROPHIES};
+use simula_matrix_framework_rust::LatinSquare;
+
+fn is_latin_square(square: &LatinSquare) -> bool {
+    let n = square.0.len();
+
+    (0..n).all(|i| {
+        let mut row: Vec<usize> = square.0[i].clone();
+        let mut column: Vec<usize> = (0..n).map(|j| square.0[j][i]).collect();
+        row.sort();
+        column.sort();
+
+        row == (0..n).collect::<Vec<usize>>() && column == (0..n).collect::<Vec<usize>>()
+    })
+}
+
+#[test]
+fn test_conjugates() {
+    let square = LatinSquare(vec![
+        vec![1, 0, 2, 3],
+        vec![2, 3, 1, 0],
+        vec![0, 2, 3, 1],
+        vec![3, 1, 0, 2],
+    ]);
+
+    for p in PARASTROPHIES {
+        assert!(is_latin_square(&square.conjugate(p)));
+    }
+
+    // square[0][1] = 0, so the conjugate (s, c, r) has 0 in row 0, column 1, and (c, s, r) has 0 in row 1, column 0.
+    assert_eq!(square.conjugate(Parastrophy::SymbolColumnRow).0[0][1], 0);
+    assert_eq!(square.conjugate(Parastrophy::ColumnSymbolRow).0[1][0], 0);
+
+    // The transpositions are their own inverses, and the two cycles are inverse to each other.
+    for p in [
+        Parastrophy::Transpose,
+        Parastrophy::RowSymbolColumn,
+        Parastrophy::SymbolColumnRow,
+    ] {
+        assert_eq!(square.conjugate(p).conjugate(p), square);
+    }
+
+    assert_eq!(
+        square
+            .conjugate(Parastrophy::ColumnSymbolRow)
+            .conjugate(Parastrophy::SymbolRowColumn),
+        square
+    );
+}
+
+#[test]
+fn test_conjugate_symmetry() {
+    for n in 2..=4 {
+        for square in LatinSquare::generate_all(n) {
+            let symmetry = square.conjugate_symmetry();
+
+            assert!(symmetry.contains(&Parastrophy::Identity));
+            assert_eq!(
+                symmetry.contains(&Parastrophy::Transpose),
+                square.commutative()
+            );
+            assert_eq!(symmetry.len() == 6, square.totally_symmetric());
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use simula_matrix_framework_rust::{LatinSquare, PartialLatinSquare};
+
+#[test]
+fn test_count_completions() {
+    // Every latin square completes the empty grid.
+    for n in 1..=4 {
+        assert_eq!(
+            PartialLatinSquare::empty(n).count_completions(),
+            LatinSquare::count_all(n).unwrap()
+        );
+    }
+
+    // Fixing the first row and column leaves the reduced latin squares.
+    let mut partial = PartialLatinSquare::empty(5);
+    for i in 0..5 {
+        partial.0[0][i] = Some(i);
+        partial.0[i][0] = Some(i);
+    }
+
+    let mut completions = partial.completions();
+    completions.sort();
+    assert_eq!(completions, LatinSquare::generate_reduced(5));
+}
+
+#[test]
+fn test_complete() {
+    let partial: PartialLatinSquare = "
+        0 . . . 4
+        . . 3 . .
+        . 4 . . .
+        . . . 1 .
+        3 . . . 0
+    "
+    .parse()
+    .unwrap();
+
+    let square = partial.complete().unwrap();
+
+    for (r, row) in partial.0.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            if let Some(s) = cell {
+                assert_eq!(square.0[r][c], *s);
+            }
+        }
+    }
+    assert_eq!(
+        PartialLatinSquare::from(&square).to_latin_square(),
+        Some(square)
+    );
+}
+
+#[test]
+fn test_no_completion() {
+    // The last cell of the first row has to be 3, which is already in its column.
+    let partial: PartialLatinSquare = "0 1 2 .\n. . . 3\n. . . .\n. . . .".parse().unwrap();
+    assert!(partial.is_valid());
+    assert_eq!(partial.complete(), None);
+    assert_eq!(partial.count_completions(), 0);
+
+    // A symbol twice in a row.
+    let invalid: PartialLatinSquare = "1 1\n. .".parse().unwrap();
+    assert!(!invalid.is_valid());
+    assert_eq!(invalid.count_completions(), 0);
+
+    assert!("0 1\n2".parse::<PartialLatinSquare>().is_err());
+    assert!("0 x\n. .".parse::<PartialLatinSquare>().is_err());
+}
+
+#[test]
+fn test_large_order() {
+    // The cyclic group of order 65 with the last row left out, which has more symbols than a u64 has bits.
+    let n = 65;
+    let text: Vec<String> = (0..n)
+        .map(|r| {
+            let cells: Vec<String> = (0..n)
+                .map(|c| match r {
+                    64 => ".".to_string(),
+                    _ => ((r + c) % n).to_string(),
+                })
+                .collect();
+            cells.join(" ")
+        })
+        .collect();
+
+    let partial: PartialLatinSquare = text.join("\n").parse().unwrap();
+    assert!(partial.is_valid());
+
+    let square = partial.complete().unwrap();
+    assert_eq!(
+        square.0[64],
+        (0..n).map(|c| (64 + c) % n).collect::<Vec<usize>>()
+    );
+
+    let mut invalid = partial.clone();
+    invalid.0[64][0] = Some(0);
+    assert!(!invalid.is_valid());
+}
+
+#[test]
+fn test_display() {
+    let text = "0 . 2\n. . .\n2 . 1";
+    let partial: PartialLatinSquare = text.parse().unwrap();
+
+    assert_eq!(partial.to_string(), text);
+    assert_eq!(partial.filled(), 4);
+}
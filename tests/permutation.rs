@@ -0,0 +1,97 @@
+use simula_matrix_framework_rust::structs::permutation::generate_all_permutations;
+use simula_matrix_framework_rust::structs::CycleNotationError;
+use simula_matrix_framework_rust::{LaTeX, Permutation};
+
+#[test]
+fn test_iter_all_matches_recursive_generation() {
+    for n in 0..=6 {
+        let iterated: Vec<Vec<usize>> = Permutation::iter_all(n).map(|p| p.0).collect();
+
+        assert_eq!(iterated, generate_all_permutations(n));
+    }
+}
+
+#[test]
+fn test_rank_and_unrank_follow_generate_all() {
+    for n in 0..=6 {
+        for (i, p) in Permutation::generate_all(n).iter().enumerate() {
+            assert_eq!(p.rank(), i);
+            assert_eq!(&Permutation::unrank(n, i), p);
+        }
+    }
+}
+
+#[test]
+fn test_cycle_structure() {
+    // 0 -> 2 -> 0 and 1 -> 3 -> 4 -> 1.
+    let p = Permutation(vec![2, 3, 0, 4, 1, 5]);
+
+    assert_eq!(p.cycles(), vec![vec![0, 2], vec![1, 3, 4], vec![5]]);
+    assert_eq!(p.cycle_type(), vec![3, 2, 1]);
+    assert_eq!(p.order(), 6);
+    assert_eq!(p.sign(), -1);
+    assert_eq!(p.cycle_notation(), "(0 2)(1 3 4)");
+    assert_eq!(format!("{:#}", p), "(0 2)(1 3 4)");
+    assert_eq!(format!("{:#}", Permutation(vec![0, 1, 2])), "()");
+}
+
+#[test]
+fn test_inverse() {
+    for p in Permutation::generate_all(4) {
+        let identity = Permutation(vec![0, 1, 2, 3]);
+
+        assert_eq!(p.compose(&p.inverse()), identity);
+        assert_eq!(p.inverse().compose(&p), identity);
+        assert_eq!(p.inverse().sign(), p.sign());
+        assert_eq!(p.inverse().order(), p.order());
+    }
+}
+
+#[test]
+fn test_parse_cycle_notation() {
+    let p = Permutation::from_cycle_notation(6, "(0 2)(1, 3, 4)").unwrap();
+
+    assert_eq!(p, Permutation(vec![2, 3, 0, 4, 1, 5]));
+
+    for p in Permutation::generate_all(5) {
+        assert_eq!(
+            Permutation::from_cycle_notation(5, &p.cycle_notation()),
+            Ok(p)
+        );
+    }
+
+    assert_eq!(
+        Permutation::from_cycle_notation(3, "(0 3)"),
+        Err(CycleNotationError::ElementTooLarge(3))
+    );
+    assert_eq!(
+        Permutation::from_cycle_notation(3, "(0 1)(1 2)"),
+        Err(CycleNotationError::RepeatedElement(1))
+    );
+    assert!(Permutation::from_cycle_notation(3, "(0 1").is_err());
+    assert!(Permutation::from_cycle_notation(3, "0 1").is_err());
+}
+
+#[test]
+fn test_even_permutations_are_half() {
+    let even = Permutation::generate_all(5)
+        .iter()
+        .filter(|p| p.is_even())
+        .count();
+
+    assert_eq!(even, 60);
+}
+
+#[test]
+fn test_latex() {
+    let p = Permutation::from_cycle_notation(5, "(0 2)(1 3 4)").unwrap();
+
+    assert_eq!(
+        p.latex(),
+        "\\begin{tabular}{@{}c@{}}\n    \\( \\begin{smallmatrix}\n\
+         0 & 1 & 2 & 3 & 4\\\\\n\
+         2 & 3 & 0 & 4 & 1\n\
+         \\end{smallmatrix} \\)\\\\\n    \\( (0\\ 2)(1\\ 3\\ 4) \\)\n\
+         \\end{tabular}"
+    );
+}
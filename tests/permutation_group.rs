@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use simula_matrix_framework_rust::{Permutation, PermutationGroup};
+
+fn parse(n: usize, text: &str) -> Permutation {
+    Permutation::from_cycle_notation(n, text).unwrap()
+}
+
+// The group generated by the permutations, found by closing them under composition.
+fn closure(n: usize, generators: &[Permutation]) -> HashSet<Permutation> {
+    let mut seen: HashSet<Permutation> = HashSet::from([Permutation((0..n).collect())]);
+    let mut queue: Vec<Permutation> = seen.iter().cloned().collect();
+
+    while let Some(g) = queue.pop() {
+        for s in generators {
+            let h = s.compose(&g);
+
+            if seen.insert(h.clone()) {
+                queue.push(h);
+            }
+        }
+    }
+
+    seen
+}
+
+#[test]
+fn test_orders() {
+    let symmetric =
+        PermutationGroup::from_generators(6, vec![parse(6, "(0 1)"), parse(6, "(0 1 2 3 4 5)")]);
+    assert_eq!(symmetric.order(), 720);
+
+    let alternating =
+        PermutationGroup::from_generators(5, vec![parse(5, "(0 1 2)"), parse(5, "(2 3 4)")]);
+    assert_eq!(alternating.order(), 60);
+
+    let trivial = PermutationGroup::from_generators(4, vec![]);
+    assert_eq!(trivial.order(), 1);
+    assert_eq!(trivial.elements(), vec![Permutation(vec![0, 1, 2, 3])]);
+}
+
+#[test]
+fn test_elements_and_membership_match_closure() {
+    let generators = vec![parse(7, "(0 1 2)(3 4)"), parse(7, "(1 5)(2 6)")];
+    let group = PermutationGroup::from_generators(7, generators.clone());
+    let expected = closure(7, &generators);
+
+    let elements = group.elements();
+    assert_eq!(elements.len(), expected.len());
+    assert_eq!(group.order(), expected.len());
+    assert!(elements.windows(2).all(|w| w[0].rank() < w[1].rank()));
+
+    for p in Permutation::iter_all(7) {
+        assert_eq!(group.contains(&p), expected.contains(&p));
+    }
+}
+
+#[test]
+fn test_orbits_and_stabilizers() {
+    // The dihedral group of the square 0 1 2 3, acting trivially on 4 and 5, and swapping them with (4 5).
+    let group = PermutationGroup::from_generators(
+        6,
+        vec![parse(6, "(0 1 2 3)"), parse(6, "(1 3)"), parse(6, "(4 5)")],
+    );
+
+    assert_eq!(group.order(), 16);
+    assert_eq!(group.orbits(), vec![vec![0, 1, 2, 3], vec![4, 5]]);
+
+    // Orbit-stabilizer theorem.
+    for x in 0..6 {
+        let stabilizer = group.stabilizer(x);
+
+        assert_eq!(stabilizer.order() * group.orbit(x).len(), group.order());
+        assert!(stabilizer
+            .elements()
+            .iter()
+            .all(|p| p.0[x] == x && group.contains(p)));
+    }
+}
+
+#[test]
+fn test_random_elements_are_in_the_group() {
+    let group = PermutationGroup::from_generators(
+        8,
+        vec![parse(8, "(0 1 2 3 4 5 6)"), parse(8, "(0 1)(6 7)")],
+    );
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        assert!(group.contains(&group.random_element(&mut rng)));
+    }
+}
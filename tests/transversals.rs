@@ -0,0 +1,51 @@
+use simula_matrix_framework_rust::structs::latin_square;
+use simula_matrix_framework_rust::{LatinSquare, Permutation};
+
+mod common;
+use common::{cyclic_group, elementary_abelian_group};
+
+#[test]
+fn test_count_transversals() {
+    // Cyclic groups of even order have no transversals.
+    let counts = [(3, 3), (4, 0), (5, 15), (6, 0), (7, 133)];
+    for (n, count) in counts {
+        assert_eq!(cyclic_group(n).count_transversals(), count);
+        assert_eq!(cyclic_group(n).transversals().len(), count);
+    }
+    assert_eq!(elementary_abelian_group(4).count_transversals(), 8);
+}
+
+#[test]
+fn test_orthogonal_mates() {
+    let z5 = cyclic_group(5);
+    let mates = z5.orthogonal_mates();
+
+    assert!(!mates.is_empty());
+    for mate in mates.iter() {
+        assert!(z5.is_orthogonal(mate));
+        assert_eq!(mate.0[0], vec![0, 1, 2, 3, 4]);
+    }
+
+    // A square is never orthogonal to itself, unless n = 1.
+    assert!(!z5.is_orthogonal(&z5));
+    assert!(cyclic_group(4).orthogonal_mates().is_empty());
+
+    // Every normalized latin square of order 4 orthogonal to the Klein four-group.
+    let klein = elementary_abelian_group(4);
+    let expected: Vec<LatinSquare> = LatinSquare::generate_all(4)
+        .into_iter()
+        .filter(|s| s.0[0] == vec![0, 1, 2, 3] && klein.is_orthogonal(s))
+        .collect();
+    assert_eq!(klein.orthogonal_mates(), expected);
+    assert_eq!(expected.len(), 2);
+}
+
+#[test]
+fn test_transversal_column() {
+    let perms = Permutation::generate_all(4);
+    let classes = LatinSquare::isomorphism_classes(4);
+
+    for c in latin_square::classify_all_isomorphism_classes(&classes, &perms) {
+        assert_eq!(c.transversals, c.square.count_transversals());
+    }
+}
@@ -0,0 +1,111 @@
+use simula_matrix_framework_rust::structs::{latin_square, validation, Sidedness, ValidationError};
+use simula_matrix_framework_rust::{LatinSquare, Permutation};
+
+#[test]
+fn test_classifications_are_valid() {
+    for n in 1..=4 {
+        let perms = Permutation::generate_all(n);
+        let classes = LatinSquare::isomorphism_classes(n);
+        let classification = latin_square::classify_all_isomorphism_classes(&classes, &perms);
+
+        assert_eq!(
+            validation::validate_classifications(&classification, &perms),
+            vec![]
+        );
+    }
+}
+
+#[test]
+fn test_violations_are_reported() {
+    let perms = Permutation::generate_all(3);
+    let squares = LatinSquare::generate_all(3);
+    let mut classification = latin_square::classify_all_latin_squares(&squares, &perms);
+
+    // Record the transposition (0 1), rank 2, as an automorphism of the first square, the cyclic group.
+    let c = &mut classification[0];
+    c.all_permutations_all_affine_automorphisms[2].0 = true;
+    c.all_permutations_all_affine_automorphisms[2]
+        .1
+        .push((2, 0, Sidedness::Left));
+
+    let errors = validation::validate_classification(c, &perms);
+
+    assert!(errors.contains(&ValidationError::AutomorphismFlag {
+        square: 0,
+        permutation: 2,
+        recorded: true,
+    }));
+    assert!(errors.contains(&ValidationError::NotAnAutomorphism {
+        square: 0,
+        permutation: 2,
+        automorphism: 2,
+    }));
+    assert!(errors.contains(&ValidationError::MissingAffineMaps {
+        square: 0,
+        automorphism: 2,
+        found: 1,
+        expected: 0,
+    }));
+
+    // The square now has a fingerprint of its own, whose sum for the transposition does not match the square.
+    let errors = validation::validate_automorphism_counts(&classification, &perms);
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ValidationError::AutomorphismCount {
+            permutation: 2,
+            recorded: 1,
+            found: 0,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn test_wrong_identity_count_is_reported() {
+    let perms = Permutation::generate_all(3);
+    let squares = LatinSquare::generate_all(3);
+    let mut classification = latin_square::classify_all_latin_squares(&squares, &perms);
+
+    // Drop the identity from the automorphisms of the first square, the cyclic group.
+    classification[0].all_permutations_all_affine_automorphisms[0] = (false, vec![]);
+
+    let errors = validation::validate_automorphism_counts(&classification, &perms);
+
+    // The square is now alone with its fingerprint, whose identity sum is 0 instead of 1.
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ValidationError::AutomorphismCount {
+            permutation: 0,
+            recorded: 0,
+            found: 1,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn test_inconsistent_fingerprint_is_reported() {
+    let perms = Permutation::generate_all(3);
+    let squares = LatinSquare::generate_all(3);
+    let mut classification = latin_square::classify_all_latin_squares(&squares, &perms);
+
+    // Give a square with other automorphisms the fingerprint of the first square.
+    let first = classification[0]
+        .all_permutations_all_affine_automorphisms
+        .clone();
+    let fingerprint = classification[0].fingerprint_no_structure();
+    let other = classification
+        .iter_mut()
+        .find(|c| c.fingerprint_no_structure() != fingerprint)
+        .unwrap();
+    other.all_permutations_all_affine_automorphisms = first;
+
+    let errors = validation::validate_automorphism_counts(&classification, &perms);
+
+    assert!(errors
+        .iter()
+        .any(|e| matches!(e, ValidationError::InconsistentAutomorphismCount { .. })));
+    assert!(errors
+        .iter()
+        .any(|e| matches!(e, ValidationError::AutomorphismCount { .. })));
+}